@@ -0,0 +1,101 @@
+use crate::element::Element;
+use crate::error::MatrixError;
+use crate::kernel;
+use crate::matrix::Matrix;
+use crate::pool::MatMulPool;
+
+/// The layout of a strided batch: `count` products of a `rows` x `depth` matrix
+/// by a `depth` x `cols` matrix, each operand and result stored row-major and
+/// back to back in one buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BatchShape {
+    pub count: usize,
+    pub rows: usize,
+    pub depth: usize,
+    pub cols: usize,
+}
+
+impl<T: Element> Matrix<T> {
+    /// Computes `a[i] * b[i]` for every `i` on the worker threads of
+    /// [`MatMulPool::global`]. See [`MatMulPool::batch_mul`].
+    pub fn batch_mul(a: &[Matrix<T>], b: &[Matrix<T>]) -> Result<Vec<Matrix<T>>, MatrixError> {
+        MatMulPool::global().batch_mul(a, b)
+    }
+}
+
+impl MatMulPool {
+    /// Computes `a[i] * b[i]` for every `i`.
+    ///
+    /// Meant for many small products: the workers split the batch between them
+    /// and run each product on a single thread, instead of splitting every
+    /// product across threads.
+    ///
+    /// Returns [`MatrixError::BatchLengthMismatch`] if `a` and `b` differ in
+    /// length, and [`MatrixError::ShapeMismatch`] if any pair cannot be
+    /// multiplied. Nothing is computed in either case.
+    pub fn batch_mul<T: Element>(&self, a: &[Matrix<T>], b: &[Matrix<T>]) -> Result<Vec<Matrix<T>>, MatrixError> {
+        if a.len() != b.len() {
+            return Err(MatrixError::BatchLengthMismatch { left: a.len(), right: b.len() })
+        }
+
+        if let Some((m1, m2)) = a.iter().zip(b).find(|(m1, m2)| m1.cols() != m2.rows()) {
+            return Err(m1.shape_mismatch(m2))
+        }
+
+        let mut c: Vec<Matrix<T>> = a.iter().zip(b).map(|(m1, m2)| Matrix::zeros(m1.rows(), m2.cols())).collect();
+
+        if !c.is_empty() {
+            self.run_chunked(&mut c, 1, self.thread_count(), |start, c| {
+                for (index, m) in c.iter_mut().enumerate() {
+                    let (m1, m2) = (&a[start + index], &b[start + index]);
+                    kernel::naive::<T>(m1.cells(), m2.cells(), m.cells_mut(), m1.cols(), m2.cols());
+                }
+            });
+        }
+
+        Ok(c)
+    }
+
+    /// Like [`MatMulPool::batch_mul`], but for a batch laid out as described by
+    /// `shape` in the contiguous buffers `a`, `b` and `c`. Overwrites `c`.
+    ///
+    /// Returns [`MatrixError::BadCellCount`] if a buffer does not hold exactly
+    /// `shape.count` matrices of its shape.
+    pub fn batch_mul_strided<T: Element>(
+        &self,
+        a: &[T],
+        b: &[T],
+        c: &mut [T],
+        shape: BatchShape,
+    ) -> Result<(), MatrixError> {
+        let a_size = shape.rows * shape.depth;
+        let b_size = shape.depth * shape.cols;
+        let c_size = shape.rows * shape.cols;
+
+        for (buffer, size) in [(a.len(), a_size), (b.len(), b_size), (c.len(), c_size)] {
+            if buffer != shape.count * size {
+                return Err(MatrixError::BadCellCount { expected: shape.count * size, got: buffer })
+            }
+        }
+
+        if shape.count == 0 || c_size == 0 {
+            return Ok(())
+        }
+
+        self.run_chunked(c, c_size, self.thread_count(), |start, c| {
+            for (index, c) in c.chunks_exact_mut(c_size).enumerate() {
+                let i = start + index;
+                c.fill(T::zero());
+                kernel::naive::<T>(
+                    &a[i * a_size..(i + 1) * a_size],
+                    &b[i * b_size..(i + 1) * b_size],
+                    c,
+                    shape.depth,
+                    shape.cols,
+                );
+            }
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,254 @@
+use crate::element::Element;
+use crate::error::MatrixError;
+use crate::matrix::Matrix;
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// How many rows of the right operand the Method of Four Russians combines
+/// into one lookup table. Tables hold `2^GROUP_BITS` rows, and since the group
+/// divides the word size, a group of bits never straddles two words.
+const GROUP_BITS: usize = 8;
+
+/// A matrix of bits, with every row packed into `u64` words.
+///
+/// Uses the same `(row, col)` convention as [`Matrix`], and a 32nd of the
+/// memory of a `Matrix<i32>` holding zeros and ones. Bit `col % 64` of word
+/// `col / 64` of a row holds column `col`; the unused bits of the last word
+/// are always zero.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitMatrix {
+    rows: usize,
+    cols: usize,
+    words: Vec<u64>,
+}
+
+impl BitMatrix {
+    /// Builds a `rows` x `cols` matrix with every bit cleared.
+    pub fn zeros(rows: usize, cols: usize) -> BitMatrix {
+        BitMatrix {
+            rows,
+            cols,
+            words: vec![0; rows * cols.div_ceil(WORD_BITS)],
+        }
+    }
+
+    /// Builds the `size` x `size` identity matrix.
+    pub fn identity(size: usize) -> BitMatrix {
+        let mut m = BitMatrix::zeros(size, size);
+        for i in 0..size {
+            m.put(i, i, true);
+        }
+
+        m
+    }
+
+    /// Packs `m`, setting the bits of its nonzero cells.
+    pub fn from_matrix<T: Element>(m: &Matrix<T>) -> BitMatrix {
+        let mut bits = BitMatrix::zeros(m.rows(), m.cols());
+
+        for (index, &cell) in m.cells().iter().enumerate() {
+            if cell != T::zero() {
+                bits.put(index / m.cols(), index % m.cols(), true);
+            }
+        }
+
+        bits
+    }
+
+    /// Unpacks into a matrix of ones and zeros.
+    pub fn to_matrix<T: Element>(&self) -> Matrix<T> {
+        let mut m = Matrix::zeros(self.rows, self.cols);
+
+        for row in 0..self.rows {
+            for col in 0..self.cols {
+                if self.bit(row, col) {
+                    m[(row, col)] = T::one();
+                }
+            }
+        }
+
+        m
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// The shape as `(rows, cols)`.
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// Returns the bit at (`row`, `col`).
+    ///
+    /// Returns [`MatrixError::IndexOutOfBounds`] unless `row < rows` and `col < cols`.
+    pub fn get(&self, row: usize, col: usize) -> Result<bool, MatrixError> {
+        if row >= self.rows || col >= self.cols {
+            return Err(self.out_of_bounds(row, col))
+        }
+
+        Ok(self.bit(row, col))
+    }
+
+    /// Replaces the bit at (`row`, `col`) and returns its previous value.
+    ///
+    /// Returns [`MatrixError::IndexOutOfBounds`] unless `row < rows` and `col < cols`.
+    pub fn set(&mut self, row: usize, col: usize, value: bool) -> Result<bool, MatrixError> {
+        if row >= self.rows || col >= self.cols {
+            return Err(self.out_of_bounds(row, col))
+        }
+
+        let previous = self.bit(row, col);
+        self.put(row, col, value);
+
+        Ok(previous)
+    }
+
+    /// Multiplies `self` by `m` over GF(2), where `+` is XOR and `*` is AND.
+    ///
+    /// Uses the Method of Four Russians: the rows of `m` are taken eight at a
+    /// time, every XOR of a subset of them is tabulated once, and each row of
+    /// the result then costs one table lookup per group instead of one row
+    /// operation per set bit.
+    ///
+    /// Returns [`MatrixError::ShapeMismatch`] if `self.cols != m.rows`.
+    pub fn mul_gf2(&self, m: &BitMatrix) -> Result<BitMatrix, MatrixError> {
+        self.four_russians(m, |x, y| x ^ y)
+    }
+
+    /// Multiplies `self` by `m` over the boolean semiring, where `+` is OR and
+    /// `*` is AND, with the Method of Four Russians as in [`BitMatrix::mul_gf2`].
+    ///
+    /// Bit (`i`, `j`) of the result is set if some `k` has both bit (`i`, `k`)
+    /// of `self` and bit (`k`, `j`) of `m` set.
+    ///
+    /// Returns [`MatrixError::ShapeMismatch`] if `self.cols != m.rows`.
+    pub fn mul_bool(&self, m: &BitMatrix) -> Result<BitMatrix, MatrixError> {
+        self.four_russians(m, |x, y| x | y)
+    }
+
+    /// The transitive closure of a square adjacency matrix: bit (`i`, `j`) is
+    /// set if there is a path of one or more edges from `i` to `j`.
+    ///
+    /// Squares `I | A` until it stops changing, which takes about `log2(rows)`
+    /// boolean products, and then multiplies by `A` once more.
+    ///
+    /// Returns [`MatrixError::NotSquare`] if the matrix is not square.
+    pub fn transitive_closure(&self) -> Result<BitMatrix, MatrixError> {
+        if self.rows != self.cols {
+            return Err(MatrixError::NotSquare { rows: self.rows, cols: self.cols })
+        }
+
+        let mut reach = self.clone();
+        for i in 0..self.rows {
+            reach.put(i, i, true);
+        }
+
+        loop {
+            let squared = reach.mul_bool(&reach)?;
+            if squared == reach {
+                break
+            }
+            reach = squared;
+        }
+
+        self.mul_bool(&reach)
+    }
+
+    fn four_russians(&self, m: &BitMatrix, combine: impl Fn(u64, u64) -> u64) -> Result<BitMatrix, MatrixError> {
+        if self.cols != m.rows {
+            return Err(self.shape_mismatch(m))
+        }
+
+        let mut c = BitMatrix::zeros(self.rows, m.cols);
+        let width = m.row_words();
+        let mut table = vec![0; width << GROUP_BITS];
+
+        for group_start in (0..m.rows).step_by(GROUP_BITS) {
+            let group_len = GROUP_BITS.min(m.rows - group_start);
+
+            // Entry `index` combines the rows of the group picked by the bits of
+            // `index`, built from the entry without its lowest bit.
+            for index in 1usize..1 << group_len {
+                let row = m.row(group_start + index.trailing_zeros() as usize);
+                let (built, entry) = table.split_at_mut(index * width);
+                let without_lowest = &built[(index & (index - 1)) * width..][..width];
+
+                for ((entry, &x), &y) in entry[..width].iter_mut().zip(without_lowest).zip(row) {
+                    *entry = combine(x, y);
+                }
+            }
+
+            let (word, shift) = (group_start / WORD_BITS, group_start % WORD_BITS);
+            for i in 0..self.rows {
+                let index = (self.row(i)[word] >> shift) as usize & ((1 << group_len) - 1);
+                if index == 0 {
+                    continue
+                }
+
+                let entry = &table[index * width..(index + 1) * width];
+                for (cell, &x) in c.row_mut(i).iter_mut().zip(entry) {
+                    *cell = combine(*cell, x);
+                }
+            }
+        }
+
+        Ok(c)
+    }
+
+    fn row_words(&self) -> usize {
+        self.cols.div_ceil(WORD_BITS)
+    }
+
+    fn row(&self, row: usize) -> &[u64] {
+        let width = self.row_words();
+        &self.words[row * width..(row + 1) * width]
+    }
+
+    fn row_mut(&mut self, row: usize) -> &mut [u64] {
+        let width = self.row_words();
+        &mut self.words[row * width..(row + 1) * width]
+    }
+
+    fn bit(&self, row: usize, col: usize) -> bool {
+        self.row(row)[col / WORD_BITS] >> (col % WORD_BITS) & 1 == 1
+    }
+
+    fn put(&mut self, row: usize, col: usize, value: bool) {
+        let word = &mut self.row_mut(row)[col / WORD_BITS];
+        let mask = 1 << (col % WORD_BITS);
+
+        if value {
+            *word |= mask;
+        } else {
+            *word &= !mask;
+        }
+    }
+
+    fn out_of_bounds(&self, row: usize, col: usize) -> MatrixError {
+        MatrixError::IndexOutOfBounds { row, col, rows: self.rows, cols: self.cols }
+    }
+
+    fn shape_mismatch(&self, m: &BitMatrix) -> MatrixError {
+        MatrixError::ShapeMismatch {
+            left: self.shape(),
+            right: m.shape(),
+        }
+    }
+}
+
+impl<T: Element> Matrix<T> {
+    /// The transitive closure of a square adjacency matrix, where any nonzero
+    /// cell is an edge. The result holds ones and zeros.
+    ///
+    /// Computed on a [`BitMatrix`] with [`BitMatrix::transitive_closure`].
+    ///
+    /// Returns [`MatrixError::NotSquare`] if the matrix is not square.
+    pub fn transitive_closure(&self) -> Result<Matrix<T>, MatrixError> {
+        BitMatrix::from_matrix(self).transitive_closure().map(|m| m.to_matrix())
+    }
+}
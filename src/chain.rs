@@ -0,0 +1,137 @@
+use std::borrow::Cow;
+use std::fmt;
+
+use crate::element::Element;
+use crate::error::MatrixError;
+use crate::matrix::Matrix;
+
+/// The order in which a chain of matrices is multiplied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChainOrder {
+    /// The matrix at this index of the chain.
+    Matrix(usize),
+    /// The product of two sub-chains.
+    Product(Box<ChainOrder>, Box<ChainOrder>),
+}
+
+impl fmt::Display for ChainOrder {
+    /// Writes the parenthesization, e.g. `((A0 A1) A2)`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ChainOrder::Matrix(index) => write!(f, "A{}", index),
+            ChainOrder::Product(left, right) => write!(f, "({} {})", left, right),
+        }
+    }
+}
+
+/// The cheapest order for a matrix chain and what it costs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChainPlan {
+    pub order: ChainOrder,
+    /// Floating point operations the order needs, counting a multiply-add as two.
+    /// Saturates at `u128::MAX` for absurdly large shapes.
+    pub flops: u128,
+}
+
+impl ChainPlan {
+    /// Finds the order that needs the fewest operations to multiply matrices of
+    /// the given `(rows, cols)` shapes, with the classic dynamic program over
+    /// all parenthesizations.
+    ///
+    /// Returns [`MatrixError::EmptyChain`] if `shapes` is empty and
+    /// [`MatrixError::ShapeMismatch`] if two neighbours cannot be multiplied.
+    pub fn new(shapes: &[(usize, usize)]) -> Result<ChainPlan, MatrixError> {
+        if shapes.is_empty() {
+            return Err(MatrixError::EmptyChain)
+        }
+
+        if let Some(pair) = shapes.windows(2).find(|pair| pair[0].1 != pair[1].0) {
+            return Err(MatrixError::ShapeMismatch { left: pair[0], right: pair[1] })
+        }
+
+        let n = shapes.len();
+        // dims[i] x dims[i + 1] is the shape of matrix i.
+        let dims: Vec<u128> = shapes
+            .iter()
+            .map(|&(rows, _)| rows as u128)
+            .chain([shapes[n - 1].1 as u128])
+            .collect();
+
+        // cost[i][j] is the cheapest way to multiply matrices i..=j, and split[i][j]
+        // the index after which that product is split.
+        let mut cost = vec![vec![0u128; n]; n];
+        let mut split = vec![vec![0usize; n]; n];
+
+        for len in 2..=n {
+            for i in 0..=n - len {
+                let j = i + len - 1;
+                for k in i..j {
+                    let candidate = cost[i][k]
+                        .saturating_add(cost[k + 1][j])
+                        .saturating_add(2u128.saturating_mul(dims[i]).saturating_mul(dims[k + 1]).saturating_mul(dims[j + 1]));
+                    // The first split always counts, even if every cost saturates.
+                    if k == i || candidate < cost[i][j] {
+                        cost[i][j] = candidate;
+                        split[i][j] = k;
+                    }
+                }
+            }
+        }
+
+        Ok(ChainPlan {
+            order: order(&split, 0, n - 1),
+            flops: cost[0][n - 1],
+        })
+    }
+}
+
+fn order(split: &[Vec<usize>], i: usize, j: usize) -> ChainOrder {
+    if i == j {
+        return ChainOrder::Matrix(i)
+    }
+
+    let k = split[i][j];
+    ChainOrder::Product(Box::new(order(split, i, k)), Box::new(order(split, k + 1, j)))
+}
+
+impl<T: Element> Matrix<T> {
+    /// Plans the cheapest order for multiplying `chain`. See [`ChainPlan::new`].
+    pub fn chain_plan(chain: &[Matrix<T>]) -> Result<ChainPlan, MatrixError> {
+        let shapes: Vec<_> = chain.iter().map(Matrix::shape).collect();
+
+        ChainPlan::new(&shapes)
+    }
+
+    /// Multiplies `chain` in the order chosen by [`Matrix::chain_plan`], one
+    /// product at a time with [`Matrix::mul`].
+    pub fn chain_mul(chain: &[Matrix<T>]) -> Result<Matrix<T>, MatrixError> {
+        let plan = Matrix::chain_plan(chain)?;
+
+        execute(&plan.order, chain, &Matrix::mul).map(Cow::into_owned)
+    }
+
+    /// Like [`Matrix::chain_mul`], but runs every product with [`Matrix::mul_mt`].
+    pub fn chain_mul_mt(chain: &[Matrix<T>]) -> Result<Matrix<T>, MatrixError> {
+        let plan = Matrix::chain_plan(chain)?;
+
+        execute(&plan.order, chain, &Matrix::mul_mt).map(Cow::into_owned)
+    }
+}
+
+type MulFn<T> = dyn Fn(&Matrix<T>, &Matrix<T>) -> Result<Matrix<T>, MatrixError>;
+
+fn execute<'a, T: Element>(
+    order: &ChainOrder,
+    chain: &'a [Matrix<T>],
+    mul: &MulFn<T>,
+) -> Result<Cow<'a, Matrix<T>>, MatrixError> {
+    match order {
+        ChainOrder::Matrix(index) => Ok(Cow::Borrowed(&chain[*index])),
+        ChainOrder::Product(left, right) => {
+            let left = execute(left, chain, mul)?;
+            let right = execute(right, chain, mul)?;
+
+            mul(&left, &right).map(Cow::Owned)
+        }
+    }
+}
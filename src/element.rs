@@ -0,0 +1,172 @@
+use crate::simd;
+
+/// Numeric types that can be stored in a [`Matrix`](crate::Matrix) and multiplied.
+///
+/// For integer types `add`, `sub` and `mul` wrap on overflow, so results are
+/// the same in debug and release builds. See [`Overflow`](crate::Overflow) for
+/// the other policies.
+pub trait Element: Copy + PartialEq + std::fmt::Debug + Send + Sync + 'static {
+    /// The additive identity.
+    fn zero() -> Self;
+
+    /// The multiplicative identity.
+    fn one() -> Self;
+
+    fn add(self, rhs: Self) -> Self;
+
+    fn sub(self, rhs: Self) -> Self;
+
+    fn mul(self, rhs: Self) -> Self;
+
+    /// The additive inverse. Integers wrap, so `MIN` and unsigned values are
+    /// negated modulo `2^BITS`; floats flip the sign bit, including on zeros
+    /// and NaNs.
+    fn neg(self) -> Self;
+
+    /// The dot product of two slices of equal length.
+    ///
+    /// This is the inner loop of the packed kernel. `i32` and `f32` override it
+    /// with a SIMD version.
+    fn dot(a: &[Self], b: &[Self]) -> Self {
+        a.iter().zip(b).fold(Self::zero(), |acc, (&x, &y)| acc.add(x.mul(y)))
+    }
+
+    /// Adds `alpha * x` to `y` element-wise, for slices of equal length.
+    ///
+    /// This is the inner loop of the naive and blocked kernels. `i32` and `f32`
+    /// override it with a SIMD version.
+    fn axpy(alpha: Self, x: &[Self], y: &mut [Self]) {
+        for (y, &x) in y.iter_mut().zip(x) {
+            *y = y.add(alpha.mul(x));
+        }
+    }
+}
+
+/// Integer element types, which support every [`Overflow`](crate::Overflow) policy.
+pub trait Integer: Element {
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+
+    fn saturating_add(self, rhs: Self) -> Self;
+
+    fn saturating_mul(self, rhs: Self) -> Self;
+
+    /// The value as a `u64`, if it fits.
+    fn to_u64(self) -> Option<u64>;
+
+    /// The remainder of `self` modulo `p`, in `0..p`.
+    fn residue(self, p: u64) -> u64;
+
+    /// Converts a residue back. `r` must fit in `Self`, which holds for any
+    /// residue modulo a `p` that came from [`Integer::to_u64`].
+    fn from_residue(r: u64) -> Self;
+}
+
+macro_rules! impl_element {
+    (
+        $t:ty, $zero:expr, $one:expr,
+        |$x:ident, $y:ident| $add:expr, $sub:expr, $mul:expr, $neg:expr
+        $(, simd: $dot:path, $axpy:path)?
+    ) => {
+        impl Element for $t {
+            fn zero() -> Self {
+                $zero
+            }
+
+            fn one() -> Self {
+                $one
+            }
+
+            fn add(self, rhs: Self) -> Self {
+                let ($x, $y) = (self, rhs);
+                $add
+            }
+
+            fn sub(self, rhs: Self) -> Self {
+                let ($x, $y) = (self, rhs);
+                $sub
+            }
+
+            fn mul(self, rhs: Self) -> Self {
+                let ($x, $y) = (self, rhs);
+                $mul
+            }
+
+            fn neg(self) -> Self {
+                let $x = self;
+                $neg
+            }
+
+            $(
+                fn dot(a: &[Self], b: &[Self]) -> Self {
+                    $dot(a, b)
+                }
+
+                fn axpy(alpha: Self, x: &[Self], y: &mut [Self]) {
+                    $axpy(alpha, x, y)
+                }
+            )?
+        }
+    };
+}
+
+macro_rules! impl_int_element {
+    ($($t:ty $(, simd: $dot:path, $axpy:path)?);*) => {
+        $(
+            impl_element!(
+                $t, 0, 1,
+                |x, y| x.wrapping_add(y), x.wrapping_sub(y), x.wrapping_mul(y), x.wrapping_neg()
+                $(, simd: $dot, $axpy)?
+            );
+
+            impl Integer for $t {
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_add(self, rhs)
+                }
+
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, rhs)
+                }
+
+                fn saturating_add(self, rhs: Self) -> Self {
+                    <$t>::saturating_add(self, rhs)
+                }
+
+                fn saturating_mul(self, rhs: Self) -> Self {
+                    <$t>::saturating_mul(self, rhs)
+                }
+
+                fn to_u64(self) -> Option<u64> {
+                    u64::try_from(self).ok()
+                }
+
+                fn residue(self, p: u64) -> u64 {
+                    match i128::try_from(self) {
+                        Ok(value) => value.rem_euclid(p as i128) as u64,
+                        // Only u128 values above i128::MAX end up here.
+                        Err(_) => (self as u128 % p as u128) as u64,
+                    }
+                }
+
+                fn from_residue(r: u64) -> Self {
+                    r as $t
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_float_element {
+    ($($t:ty $(, simd: $dot:path, $axpy:path)?);*) => {
+        $(
+            impl_element!($t, 0.0, 1.0, |x, y| x + y, x - y, x * y, -x $(, simd: $dot, $axpy)?);
+        )*
+    };
+}
+
+impl_int_element!(
+    i8; i16; i32, simd: simd::dot_i32, simd::axpy_i32; i64; i128; isize;
+    u8; u16; u32; u64; u128; usize
+);
+impl_float_element!(f32, simd: simd::dot_f32, simd::axpy_f32; f64);
@@ -0,0 +1,75 @@
+use std::error::Error;
+use std::fmt;
+
+/// Errors returned by [`Matrix`](crate::Matrix) operations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MatrixError {
+    /// The operands of a multiplication, addition or subtraction have
+    /// incompatible shapes.
+    ///
+    /// Shapes are given as `(rows, cols)`.
+    ShapeMismatch {
+        left: (usize, usize),
+        right: (usize, usize),
+    },
+    /// The number of cells passed to a constructor does not match its dimensions.
+    BadCellCount { expected: usize, got: usize },
+    /// A cell index lies outside the matrix.
+    IndexOutOfBounds {
+        row: usize,
+        col: usize,
+        rows: usize,
+        cols: usize,
+    },
+    /// A batched multiplication was given a different number of left and
+    /// right operands.
+    BatchLengthMismatch { left: usize, right: usize },
+    /// A matrix chain product was given no matrices.
+    EmptyChain,
+    /// An operation that needs a square matrix was given a `rows` x `cols` one.
+    NotSquare { rows: usize, cols: usize },
+    /// A modular operation was given a modulus outside `1..=u64::MAX`, or a
+    /// composite modulus where a prime is required.
+    InvalidModulus,
+    /// The matrix has no inverse.
+    Singular,
+    /// An integer product with [`Overflow::Checked`](crate::Overflow::Checked)
+    /// does not fit in the element type at (`row`, `col`) of the result.
+    Overflow { row: usize, col: usize },
+}
+
+impl fmt::Display for MatrixError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MatrixError::ShapeMismatch { left, right } => write!(
+                f,
+                "shape mismatch between a {}x{} matrix and a {}x{} matrix",
+                left.0, left.1, right.0, right.1
+            ),
+            MatrixError::BadCellCount { expected, got } => {
+                write!(f, "bad cell count: expected {} cells, got {}", expected, got)
+            }
+            MatrixError::IndexOutOfBounds { row, col, rows, cols } => write!(
+                f,
+                "index ({}, {}) out of bounds for a {}x{} matrix",
+                row, col, rows, cols
+            ),
+            MatrixError::BatchLengthMismatch { left, right } => {
+                write!(f, "batch length mismatch: {} left operands but {} right operands", left, right)
+            }
+            MatrixError::EmptyChain => write!(f, "cannot multiply an empty chain of matrices"),
+            MatrixError::NotSquare { rows, cols } => {
+                write!(f, "expected a square matrix, got a {}x{} matrix", rows, cols)
+            }
+            MatrixError::InvalidModulus => {
+                write!(f, "invalid modulus: must be between 1 and {}, and prime for inverses", u64::MAX)
+            }
+            MatrixError::Singular => write!(f, "matrix is singular"),
+            MatrixError::Overflow { row, col } => {
+                write!(f, "integer overflow computing cell ({}, {}) of the product", row, col)
+            }
+        }
+    }
+}
+
+impl Error for MatrixError {}
@@ -0,0 +1,164 @@
+use crate::element::Element;
+use crate::error::MatrixError;
+use crate::matrix::Matrix;
+use crate::pool::MatMulPool;
+
+/// Whether a [`gemm`] operand is used as stored or transposed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Transpose {
+    #[default]
+    No,
+    Yes,
+}
+
+/// Computes `c = alpha * op(a) * op(b) + beta * c` on the current thread,
+/// writing into `c` without allocating.
+///
+/// `op(x)` is `x` or its transpose depending on `trans_x`. As in BLAS, `c` is
+/// not read when `beta` is zero, so it may start out holding anything.
+///
+/// Returns [`MatrixError::ShapeMismatch`] if `op(a).cols != op(b).rows`, or if
+/// `c` is not `op(a).rows` x `op(b).cols`; `c` is left untouched in that case.
+pub fn gemm<T: Element>(
+    alpha: T,
+    a: &Matrix<T>,
+    trans_a: Transpose,
+    b: &Matrix<T>,
+    trans_b: Transpose,
+    beta: T,
+    c: &mut Matrix<T>,
+) -> Result<(), MatrixError> {
+    let (op_a, op_b) = operands(a, trans_a, b, trans_b, c)?;
+
+    if c.cols() > 0 {
+        gemm_rows(alpha, &op_a, &op_b, beta, c.cells_mut(), 0);
+    }
+
+    Ok(())
+}
+
+/// Like [`gemm`], but runs on the worker threads of [`MatMulPool::global`].
+pub fn gemm_mt<T: Element>(
+    alpha: T,
+    a: &Matrix<T>,
+    trans_a: Transpose,
+    b: &Matrix<T>,
+    trans_b: Transpose,
+    beta: T,
+    c: &mut Matrix<T>,
+) -> Result<(), MatrixError> {
+    MatMulPool::global().gemm(alpha, a, trans_a, b, trans_b, beta, c)
+}
+
+impl MatMulPool {
+    /// Like [`gemm`], but spreads the rows of `c` across the workers.
+    #[allow(clippy::too_many_arguments)]
+    pub fn gemm<T: Element>(
+        &self,
+        alpha: T,
+        a: &Matrix<T>,
+        trans_a: Transpose,
+        b: &Matrix<T>,
+        trans_b: Transpose,
+        beta: T,
+        c: &mut Matrix<T>,
+    ) -> Result<(), MatrixError> {
+        let (op_a, op_b) = operands(a, trans_a, b, trans_b, c)?;
+
+        let cols = c.cols();
+        if cols > 0 {
+            self.run_chunked(c.cells_mut(), cols, self.thread_count(), |row_start, c| {
+                gemm_rows(alpha, &op_a, &op_b, beta, c, row_start);
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// A matrix operand as seen through an optional transpose.
+struct Operand<'a, T> {
+    cells: &'a [T],
+    /// The shape of the operand after the transpose.
+    rows: usize,
+    cols: usize,
+    transposed: bool,
+}
+
+impl<'a, T: Element> Operand<'a, T> {
+    fn new(m: &'a Matrix<T>, transpose: Transpose) -> Operand<'a, T> {
+        let transposed = transpose == Transpose::Yes;
+        let (rows, cols) = if transposed { (m.cols(), m.rows()) } else { m.shape() };
+
+        Operand { cells: m.cells(), rows, cols, transposed }
+    }
+
+    fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// The cell at (`row`, `col`) after the transpose.
+    fn at(&self, row: usize, col: usize) -> T {
+        if self.transposed {
+            self.cells[col * self.rows + row]
+        } else {
+            self.cells[row * self.cols + col]
+        }
+    }
+}
+
+fn operands<'a, T: Element>(
+    a: &'a Matrix<T>,
+    trans_a: Transpose,
+    b: &'a Matrix<T>,
+    trans_b: Transpose,
+    c: &Matrix<T>,
+) -> Result<(Operand<'a, T>, Operand<'a, T>), MatrixError> {
+    let op_a = Operand::new(a, trans_a);
+    let op_b = Operand::new(b, trans_b);
+
+    if op_a.cols != op_b.rows {
+        return Err(MatrixError::ShapeMismatch { left: op_a.shape(), right: op_b.shape() })
+    }
+
+    if c.shape() != (op_a.rows, op_b.cols) {
+        return Err(MatrixError::ShapeMismatch { left: (op_a.rows, op_b.cols), right: c.shape() })
+    }
+
+    Ok((op_a, op_b))
+}
+
+/// Updates the rows of `c` starting at row `row_start` of the full output.
+fn gemm_rows<T: Element>(alpha: T, op_a: &Operand<T>, op_b: &Operand<T>, beta: T, c: &mut [T], row_start: usize) {
+    let (depth, cols) = (op_a.cols, op_b.cols);
+
+    for (r, c_row) in c.chunks_exact_mut(cols).enumerate() {
+        let i = row_start + r;
+
+        if beta == T::zero() {
+            c_row.fill(T::zero());
+        } else if beta != T::one() {
+            for c_cell in c_row.iter_mut() {
+                *c_cell = beta.mul(*c_cell);
+            }
+        }
+
+        if !op_b.transposed {
+            // Rows of op(b) are contiguous, so add a scaled row per cell of op(a).
+            for k in 0..depth {
+                T::axpy(alpha.mul(op_a.at(i, k)), &op_b.cells[k * cols..(k + 1) * cols], c_row);
+            }
+        } else {
+            // Columns of op(b) are the contiguous rows of b.
+            for (j, c_cell) in c_row.iter_mut().enumerate() {
+                let b_col = &op_b.cells[j * depth..(j + 1) * depth];
+                let sum = if op_a.transposed {
+                    (0..depth).fold(T::zero(), |acc, k| acc.add(op_a.at(i, k).mul(b_col[k])))
+                } else {
+                    T::dot(&op_a.cells[i * depth..(i + 1) * depth], b_col)
+                };
+                *c_cell = c_cell.add(alpha.mul(sum));
+            }
+        }
+    }
+}
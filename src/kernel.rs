@@ -0,0 +1,124 @@
+use crate::element::Element;
+use crate::semiring::Semiring;
+
+/// Tile sizes used by the cache-blocked kernel.
+///
+/// For `C = A * B` the kernel walks the rows of `A` and `C` in tiles of `rows`,
+/// the columns of `B` and `C` in tiles of `cols` and the shared inner dimension
+/// in tiles of `depth`. Sizes of zero are treated as one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockSize {
+    pub rows: usize,
+    pub cols: usize,
+    pub depth: usize,
+}
+
+impl Default for BlockSize {
+    fn default() -> Self {
+        BlockSize { rows: 64, cols: 256, depth: 128 }
+    }
+}
+
+/// Multiplication strategy used by [`Matrix::mul_with`](crate::Matrix::mul_with).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Kernel {
+    /// The row-by-row loop of [`Matrix::mul`](crate::Matrix::mul).
+    #[default]
+    Naive,
+    /// The cache-blocked kernel of [`Matrix::mul_blocked`](crate::Matrix::mul_blocked).
+    Blocked(BlockSize),
+    /// Transposes the right operand into a packed buffer first, so every output
+    /// cell is a dot product of two contiguous slices.
+    Packed,
+}
+
+/// Accumulates `a * b` into `c` one row at a time, adding a scaled row of `b`
+/// to the output row for every cell of `a`.
+///
+/// `a` holds the rows of the left operand that correspond to the rows of `c`,
+/// each `depth` cells long, and `b` holds the whole right operand with rows of
+/// `cols` cells. All three slices are row-major.
+pub(crate) fn naive<S: Semiring>(a: &[S::Value], b: &[S::Value], c: &mut [S::Value], depth: usize, cols: usize) {
+    if depth == 0 || cols == 0 {
+        return;
+    }
+
+    for (a_row, c_row) in a.chunks_exact(depth).zip(c.chunks_exact_mut(cols)) {
+        for (&a_cell, b_row) in a_row.iter().zip(b.chunks_exact(cols)) {
+            S::axpy(a_cell, b_row, c_row);
+        }
+    }
+}
+
+/// Accumulates `a * b` into `c` one tile at a time.
+///
+/// `a` holds the rows of the left operand that correspond to the rows of `c`,
+/// each `depth` cells long, and `b` holds the whole right operand with rows of
+/// `cols` cells. All three slices are row-major.
+pub(crate) fn blocked<S: Semiring>(
+    a: &[S::Value],
+    b: &[S::Value],
+    c: &mut [S::Value],
+    depth: usize,
+    cols: usize,
+    block: BlockSize,
+) {
+    if depth == 0 || cols == 0 {
+        return;
+    }
+
+    let rows = c.len() / cols;
+    let block_rows = block.rows.max(1);
+    let block_cols = block.cols.max(1);
+    let block_depth = block.depth.max(1);
+
+    for ii in (0..rows).step_by(block_rows) {
+        let i_end = (ii + block_rows).min(rows);
+        for kk in (0..depth).step_by(block_depth) {
+            let k_end = (kk + block_depth).min(depth);
+            for jj in (0..cols).step_by(block_cols) {
+                let j_end = (jj + block_cols).min(cols);
+                for i in ii..i_end {
+                    let a_row = &a[i * depth..(i + 1) * depth];
+                    let c_row = &mut c[i * cols + jj..i * cols + j_end];
+                    for (k, &a_cell) in a_row.iter().enumerate().take(k_end).skip(kk) {
+                        S::axpy(a_cell, &b[k * cols + jj..k * cols + j_end], c_row);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Copies the `depth` x `cols` row-major matrix `b` into a new buffer holding
+/// its transpose, so that column `j` of `b` becomes the contiguous slice
+/// `[j * depth..(j + 1) * depth]`.
+pub(crate) fn pack_transposed<T: Element>(b: &[T], depth: usize, cols: usize) -> Vec<T> {
+    let mut packed = vec![T::zero(); b.len()];
+
+    for (k, b_row) in b.chunks_exact(cols.max(1)).enumerate() {
+        for (j, &b_cell) in b_row.iter().enumerate() {
+            packed[j * depth + k] = b_cell;
+        }
+    }
+
+    packed
+}
+
+/// Writes `a * b` into `c`, where `b_packed` is the right operand as returned
+/// by [`pack_transposed`].
+///
+/// `a` holds the rows of the left operand that correspond to the rows of `c`,
+/// each `depth` cells long.
+pub(crate) fn packed<S: Semiring>(a: &[S::Value], b_packed: &[S::Value], c: &mut [S::Value], depth: usize, cols: usize) {
+    if cols == 0 {
+        return;
+    }
+
+    for (i, c_row) in c.chunks_exact_mut(cols).enumerate() {
+        let a_row = &a[i * depth..(i + 1) * depth];
+        for (j, c_cell) in c_row.iter_mut().enumerate() {
+            *c_cell = S::dot(a_row, &b_packed[j * depth..(j + 1) * depth]);
+        }
+    }
+}
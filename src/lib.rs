@@ -0,0 +1,31 @@
+mod batch;
+mod bitmatrix;
+mod chain;
+mod element;
+mod error;
+mod gemm;
+mod kernel;
+mod matrix;
+mod modular;
+mod ops;
+mod overflow;
+#[cfg(feature = "rayon")]
+mod par;
+mod pool;
+mod pow;
+mod semiring;
+mod simd;
+mod strassen;
+mod vector;
+
+pub use batch::BatchShape;
+pub use bitmatrix::BitMatrix;
+pub use chain::{ChainOrder, ChainPlan};
+pub use element::{Element, Integer};
+pub use error::MatrixError;
+pub use gemm::{gemm, gemm_mt, Transpose};
+pub use kernel::{BlockSize, Kernel};
+pub use matrix::{generate_matrix, Matrix};
+pub use overflow::Overflow;
+pub use pool::{MatMulPool, WorkerStats, THREADS_ENV_VAR};
+pub use semiring::{Boolean, MaxPlus, MinPlus, Semiring, Tropical};
@@ -0,0 +1,307 @@
+use std::fmt;
+use rand::Rng;
+
+use crate::element::Element;
+use crate::error::MatrixError;
+use crate::kernel::{self, BlockSize, Kernel};
+use crate::pool::MatMulPool;
+use crate::strassen;
+
+/// A dense matrix of `T` cells stored in row-major order.
+///
+/// Every API uses the same convention: shapes are `(rows, cols)` and cells are
+/// addressed as `(row, col)`, both counted from zero. The cell at `(row, col)`
+/// is stored at index `row * cols + col`.
+#[derive(Clone)]
+pub struct Matrix<T> {
+    rows: usize,
+    cols: usize,
+    cells: Vec<T>
+}
+
+impl<T: fmt::Display> fmt::Display for Matrix<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (index, cell) in self.cells.iter().enumerate() {
+            if index % self.cols == 0 {
+                write!(f, "> ")?;
+            }
+
+            write!(f, "{:>6}", cell)?;
+
+            if (index + 1) % self.cols == 0 {
+                writeln!(f)?;
+            } else {
+                write!(f, " ")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: Element> Matrix<T> {
+    /// Builds a `rows` x `cols` matrix from row-major `cells`.
+    ///
+    /// Returns [`MatrixError::BadCellCount`] if `cells` does not hold exactly
+    /// `rows * cols` values.
+    pub fn new(rows: usize, cols: usize, cells: Vec<T>) -> Result<Matrix<T>, MatrixError> {
+        let size: usize = rows * cols;
+
+        if cells.len() != size {
+            return Err(MatrixError::BadCellCount { expected: size, got: cells.len() })
+        }
+
+        Ok(Matrix {
+            rows,
+            cols,
+            cells
+        })
+    }
+
+    /// Builds a `rows` x `cols` matrix with every cell set to zero.
+    pub fn zeros(rows: usize, cols: usize) -> Matrix<T> {
+        Matrix {
+            rows,
+            cols,
+            cells: vec![T::zero(); rows * cols],
+        }
+    }
+
+    /// Builds the `size` x `size` identity matrix.
+    pub fn identity(size: usize) -> Matrix<T> {
+        let mut m = Matrix::zeros(size, size);
+        for i in 0..size {
+            m.cells[i * size + i] = T::one();
+        }
+
+        m
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// The shape as `(rows, cols)`.
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// The cells in row-major order.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub(crate) fn cells_mut(&mut self) -> &mut [T] {
+        &mut self.cells
+    }
+
+    /// Returns the cell at (`row`, `col`).
+    ///
+    /// Returns [`MatrixError::IndexOutOfBounds`] unless `row < rows` and `col < cols`.
+    pub fn get(&self, row: usize, col: usize) -> Result<T, MatrixError> {
+        if row >= self.rows || col >= self.cols {
+            return Err(self.out_of_bounds(row, col))
+        }
+
+        // SAFETY: both coordinates were checked against the matrix dimensions above.
+        Ok(unsafe { self.get_unchecked(row, col) })
+    }
+
+    /// Replaces the cell at (`row`, `col`) and returns its previous value.
+    ///
+    /// Returns [`MatrixError::IndexOutOfBounds`] unless `row < rows` and `col < cols`.
+    pub fn set(&mut self, row: usize, col: usize, value: T) -> Result<T, MatrixError> {
+        if row >= self.rows || col >= self.cols {
+            return Err(self.out_of_bounds(row, col))
+        }
+
+        // SAFETY: both coordinates were checked against the matrix dimensions above.
+        Ok(unsafe { self.set_unchecked(row, col, value) })
+    }
+
+    /// Returns the cell at (`row`, `col`) without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// The caller must guarantee `row < rows` and `col < cols`.
+    pub unsafe fn get_unchecked(&self, row: usize, col: usize) -> T {
+        *self.cells.get_unchecked(row * self.cols + col)
+    }
+
+    /// Replaces the cell at (`row`, `col`) without bounds checking and returns
+    /// its previous value.
+    ///
+    /// # Safety
+    ///
+    /// The caller must guarantee `row < rows` and `col < cols`.
+    pub unsafe fn set_unchecked(&mut self, row: usize, col: usize, value: T) -> T {
+        let index = row * self.cols + col;
+
+        std::mem::replace(self.cells.get_unchecked_mut(index), value)
+    }
+
+    pub(crate) fn out_of_bounds(&self, row: usize, col: usize) -> MatrixError {
+        MatrixError::IndexOutOfBounds { row, col, rows: self.rows, cols: self.cols }
+    }
+
+    pub(crate) fn shape_mismatch(&self, m: &Matrix<T>) -> MatrixError {
+        MatrixError::ShapeMismatch {
+            left: self.shape(),
+            right: m.shape(),
+        }
+    }
+
+    /// Adds `m` to `self` cell by cell.
+    ///
+    /// Returns [`MatrixError::ShapeMismatch`] unless both matrices have the same shape.
+    pub fn checked_add(&self, m: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        self.zip_with(m, T::add)
+    }
+
+    /// Subtracts `m` from `self` cell by cell.
+    ///
+    /// Returns [`MatrixError::ShapeMismatch`] unless both matrices have the same shape.
+    pub fn checked_sub(&self, m: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        self.zip_with(m, T::sub)
+    }
+
+    /// Multiplies every cell by `k`.
+    pub fn scale(&self, k: T) -> Matrix<T> {
+        self.map(|cell| cell.mul(k))
+    }
+
+    /// Applies `f` to every cell.
+    pub(crate) fn map(&self, f: impl Fn(T) -> T) -> Matrix<T> {
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            cells: self.cells.iter().map(|&cell| f(cell)).collect(),
+        }
+    }
+
+    fn zip_with(&self, m: &Matrix<T>, f: impl Fn(T, T) -> T) -> Result<Matrix<T>, MatrixError> {
+        if self.shape() != m.shape() {
+            return Err(self.shape_mismatch(m))
+        }
+
+        Ok(Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            cells: self.cells.iter().zip(&m.cells).map(|(&x, &y)| f(x, y)).collect(),
+        })
+    }
+
+    /// Multiplies `self` by `m` on the current thread.
+    ///
+    /// Returns [`MatrixError::ShapeMismatch`] if `self.cols != m.rows`.
+    pub fn mul(&self, m: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        self.mul_semiring::<T>(m)
+    }
+
+    /// Multiplies `self` by `m` on the current thread with a cache-blocked kernel.
+    ///
+    /// The i/j/k loops are tiled by `block` and every tile runs over contiguous
+    /// row slices, so large products stay in cache. Gives the same result as
+    /// [`Matrix::mul`].
+    ///
+    /// Returns [`MatrixError::ShapeMismatch`] if `self.cols != m.rows`.
+    pub fn mul_blocked(&self, m: &Matrix<T>, block: BlockSize) -> Result<Matrix<T>, MatrixError> {
+        let m1 = self;
+        let m2 = m;
+
+        if m1.cols != m2.rows {
+            return Err(m1.shape_mismatch(m2))
+        }
+
+        let mut m = Matrix::new(m1.rows, m2.cols, vec![T::zero(); m1.rows * m2.cols])?;
+        kernel::blocked::<T>(&m1.cells, &m2.cells, &mut m.cells, m1.cols, m2.cols, block);
+
+        Ok(m)
+    }
+
+    /// Multiplies `self` by `m` on the current thread with Strassen's algorithm.
+    ///
+    /// Recurses with seven half-size products per level until the smallest
+    /// dimension is at most `cutoff`, then falls back to the blocked kernel. Odd
+    /// and non-square shapes are padded with zeros internally. Worth it for
+    /// square-ish matrices above roughly 1024 on a side.
+    ///
+    /// Intermediate sums and differences may leave the range of `T`, but integer
+    /// arithmetic wraps, so the result still matches [`Matrix::mul`].
+    ///
+    /// Returns [`MatrixError::ShapeMismatch`] if `self.cols != m.rows`.
+    pub fn mul_strassen(&self, m: &Matrix<T>, cutoff: usize) -> Result<Matrix<T>, MatrixError> {
+        let m1 = self;
+        let m2 = m;
+
+        if m1.cols != m2.rows {
+            return Err(m1.shape_mismatch(m2))
+        }
+
+        let cells = strassen::strassen(&m1.cells, &m2.cells, m1.rows, m1.cols, m2.cols, cutoff);
+
+        Matrix::new(m1.rows, m2.cols, cells)
+    }
+
+    /// Multiplies `self` by `m` on the current thread with the chosen `kernel`.
+    ///
+    /// Every kernel gives the same result as [`Matrix::mul`].
+    ///
+    /// Returns [`MatrixError::ShapeMismatch`] if `self.cols != m.rows`.
+    pub fn mul_with(&self, m: &Matrix<T>, kernel: Kernel) -> Result<Matrix<T>, MatrixError> {
+        match kernel {
+            Kernel::Naive => self.mul(m),
+            Kernel::Blocked(block) => self.mul_blocked(m, block),
+            Kernel::Packed => self.mul_packed(m),
+        }
+    }
+
+    fn mul_packed(&self, m: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        let m1 = self;
+        let m2 = m;
+
+        if m1.cols != m2.rows {
+            return Err(m1.shape_mismatch(m2))
+        }
+
+        let mut m = Matrix::new(m1.rows, m2.cols, vec![T::zero(); m1.rows * m2.cols])?;
+        let m2_packed = kernel::pack_transposed(&m2.cells, m2.rows, m2.cols);
+        kernel::packed::<T>(&m1.cells, &m2_packed, &mut m.cells, m1.cols, m2.cols);
+
+        Ok(m)
+    }
+
+    /// Multiplies `self` by `m` on the worker threads of [`MatMulPool::global`].
+    ///
+    /// Returns [`MatrixError::ShapeMismatch`] if `self.cols != m.rows`.
+    pub fn mul_mt(&self, m: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        MatMulPool::global().mul(self, m)
+    }
+
+    /// Like [`Matrix::mul_mt`], but splits the work into `thread_count` jobs
+    /// instead of one per worker of the global pool.
+    pub fn mul_mt_with_threads(&self, m: &Matrix<T>, thread_count: usize) -> Result<Matrix<T>, MatrixError> {
+        MatMulPool::global().mul_with_threads(self, m, thread_count)
+    }
+}
+
+/// Generates a `rows` x `cols` matrix filled with random values in `-99..99`.
+pub fn generate_matrix(rows: usize, cols: usize) -> Matrix<i32> {
+
+    let mut rng = rand::thread_rng();
+    let vec: Vec<i32> = vec![0; rows * cols];
+    let mut m = Matrix::new(rows, cols, vec).unwrap();
+
+    for i in 0..rows {
+        for j in 0..cols {
+            let rand_value = rng.gen_range(-99..99);
+            m.set(i, j, rand_value).unwrap();
+        }
+    }
+
+    m
+}
@@ -0,0 +1,165 @@
+//! Operator overloads for [`Matrix`].
+//!
+//! The operators panic where the checked methods they wrap would return an
+//! error, such as [`Matrix::mul`], [`Matrix::checked_add`] and [`Matrix::get`].
+
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::element::Element;
+use crate::error::MatrixError;
+use crate::matrix::Matrix;
+
+fn unwrap_or_panic<T>(result: Result<T, MatrixError>) -> T {
+    result.unwrap_or_else(|err| panic!("{}", err))
+}
+
+macro_rules! impl_binary_op {
+    ($op:ident, $method:ident, $checked:ident) => {
+        impl<T: Element> $op<&Matrix<T>> for &Matrix<T> {
+            type Output = Matrix<T>;
+
+            fn $method(self, rhs: &Matrix<T>) -> Matrix<T> {
+                unwrap_or_panic(Matrix::$checked(self, rhs))
+            }
+        }
+
+        impl<T: Element> $op<Matrix<T>> for Matrix<T> {
+            type Output = Matrix<T>;
+
+            fn $method(self, rhs: Matrix<T>) -> Matrix<T> {
+                unwrap_or_panic(Matrix::$checked(&self, &rhs))
+            }
+        }
+
+        impl<T: Element> $op<&Matrix<T>> for Matrix<T> {
+            type Output = Matrix<T>;
+
+            fn $method(self, rhs: &Matrix<T>) -> Matrix<T> {
+                unwrap_or_panic(Matrix::$checked(&self, rhs))
+            }
+        }
+
+        impl<T: Element> $op<Matrix<T>> for &Matrix<T> {
+            type Output = Matrix<T>;
+
+            fn $method(self, rhs: Matrix<T>) -> Matrix<T> {
+                unwrap_or_panic(Matrix::$checked(self, &rhs))
+            }
+        }
+    };
+}
+
+impl_binary_op!(Mul, mul, mul);
+impl_binary_op!(Add, add, checked_add);
+impl_binary_op!(Sub, sub, checked_sub);
+
+macro_rules! impl_assign_op {
+    ($op:ident, $method:ident, $checked:ident) => {
+        impl<T: Element> $op<&Matrix<T>> for Matrix<T> {
+            fn $method(&mut self, rhs: &Matrix<T>) {
+                *self = unwrap_or_panic(Matrix::$checked(self, rhs));
+            }
+        }
+
+        impl<T: Element> $op<Matrix<T>> for Matrix<T> {
+            fn $method(&mut self, rhs: Matrix<T>) {
+                *self = unwrap_or_panic(Matrix::$checked(self, &rhs));
+            }
+        }
+    };
+}
+
+impl_assign_op!(MulAssign, mul_assign, mul);
+impl_assign_op!(AddAssign, add_assign, checked_add);
+impl_assign_op!(SubAssign, sub_assign, checked_sub);
+
+impl<T: Element> Neg for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn neg(self) -> Matrix<T> {
+        self.map(Element::neg)
+    }
+}
+
+impl<T: Element> Neg for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn neg(self) -> Matrix<T> {
+        -&self
+    }
+}
+
+// Scalar multiplication is implemented per element type, since a blanket
+// `Mul<T> for Matrix<T>` would overlap with the matrix product impls.
+macro_rules! impl_scalar_mul {
+    ($($t:ty),*) => {
+        $(
+            impl Mul<$t> for &Matrix<$t> {
+                type Output = Matrix<$t>;
+
+                fn mul(self, rhs: $t) -> Matrix<$t> {
+                    self.scale(rhs)
+                }
+            }
+
+            impl Mul<$t> for Matrix<$t> {
+                type Output = Matrix<$t>;
+
+                fn mul(self, rhs: $t) -> Matrix<$t> {
+                    self.scale(rhs)
+                }
+            }
+
+            impl Mul<&Matrix<$t>> for $t {
+                type Output = Matrix<$t>;
+
+                fn mul(self, rhs: &Matrix<$t>) -> Matrix<$t> {
+                    rhs.scale(self)
+                }
+            }
+
+            impl Mul<Matrix<$t>> for $t {
+                type Output = Matrix<$t>;
+
+                fn mul(self, rhs: Matrix<$t>) -> Matrix<$t> {
+                    rhs.scale(self)
+                }
+            }
+
+            impl MulAssign<$t> for Matrix<$t> {
+                fn mul_assign(&mut self, rhs: $t) {
+                    for cell in self.cells_mut() {
+                        *cell = Element::mul(*cell, rhs);
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar_mul!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+impl<T: Element> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    /// Panics if (`row`, `col`) is out of bounds.
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        if row >= self.rows() || col >= self.cols() {
+            panic!("{}", self.out_of_bounds(row, col));
+        }
+
+        &self.cells()[row * self.cols() + col]
+    }
+}
+
+impl<T: Element> IndexMut<(usize, usize)> for Matrix<T> {
+    /// Panics if (`row`, `col`) is out of bounds.
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        if row >= self.rows() || col >= self.cols() {
+            panic!("{}", self.out_of_bounds(row, col));
+        }
+
+        let cols = self.cols();
+        &mut self.cells_mut()[row * cols + col]
+    }
+}
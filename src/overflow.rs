@@ -0,0 +1,87 @@
+use crate::element::{Element, Integer};
+use crate::error::MatrixError;
+use crate::kernel;
+use crate::matrix::Matrix;
+
+/// How integer products handle results that do not fit in the element type.
+///
+/// Whichever policy is picked, the result is the same in debug and release builds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Overflow {
+    /// Fail with [`MatrixError::Overflow`] naming the first offending cell.
+    Checked,
+    /// Wrap around, as [`Matrix::mul`] does.
+    #[default]
+    Wrapping,
+    /// Clamp every product and every partial sum to the range of the type.
+    Saturating,
+}
+
+impl<T: Integer> Matrix<T> {
+    /// Multiplies `self` by `m` on the current thread with the given overflow policy.
+    ///
+    /// Returns [`MatrixError::ShapeMismatch`] if `self.cols != m.rows`, and
+    /// [`MatrixError::Overflow`] if `overflow` is [`Overflow::Checked`] and a
+    /// cell does not fit in `T`.
+    pub fn mul_overflow(&self, m: &Matrix<T>, overflow: Overflow) -> Result<Matrix<T>, MatrixError> {
+        let m1 = self;
+        let m2 = m;
+
+        if m1.cols() != m2.rows() {
+            return Err(m1.shape_mismatch(m2))
+        }
+
+        if overflow == Overflow::Wrapping {
+            return m1.mul(m2)
+        }
+
+        let mut m = Matrix::zeros(m1.rows(), m2.cols());
+        if m.cols() == 0 {
+            return Ok(m)
+        }
+
+        let depth = m1.cols();
+        let m2_packed = kernel::pack_transposed(m2.cells(), depth, m2.cols());
+
+        for (i, c_row) in m.cells_mut().chunks_exact_mut(m2.cols()).enumerate() {
+            let a_row = &m1.cells()[i * depth..(i + 1) * depth];
+            for (j, c_cell) in c_row.iter_mut().enumerate() {
+                let b_col = &m2_packed[j * depth..(j + 1) * depth];
+                *c_cell = match overflow {
+                    Overflow::Checked => checked_dot(a_row, b_col)
+                        .ok_or(MatrixError::Overflow { row: i, col: j })?,
+                    _ => saturating_dot(a_row, b_col),
+                };
+            }
+        }
+
+        Ok(m)
+    }
+
+    /// Multiplies `self` by `m` on the current thread, converting every cell to
+    /// the wider type `W` first and accumulating in it, for example `i32` into
+    /// `i64` so that no realistic product overflows.
+    ///
+    /// Returns [`MatrixError::ShapeMismatch`] if `self.cols != m.rows`.
+    pub fn mul_widening<W: Element + From<T>>(&self, m: &Matrix<T>) -> Result<Matrix<W>, MatrixError> {
+        if self.cols() != m.rows() {
+            return Err(self.shape_mismatch(m))
+        }
+
+        self.widen::<W>().mul(&m.widen::<W>())
+    }
+
+    fn widen<W: Element + From<T>>(&self) -> Matrix<W> {
+        let cells = self.cells().iter().map(|&cell| W::from(cell)).collect();
+
+        Matrix::new(self.rows(), self.cols(), cells).unwrap()
+    }
+}
+
+fn checked_dot<T: Integer>(a: &[T], b: &[T]) -> Option<T> {
+    a.iter().zip(b).try_fold(T::zero(), |acc, (&x, &y)| acc.checked_add(x.checked_mul(y)?))
+}
+
+fn saturating_dot<T: Integer>(a: &[T], b: &[T]) -> T {
+    a.iter().zip(b).fold(T::zero(), |acc, (&x, &y)| acc.saturating_add(x.saturating_mul(y)))
+}
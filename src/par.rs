@@ -0,0 +1,50 @@
+use rayon::prelude::*;
+use rayon::ThreadPool;
+
+use crate::element::Element;
+use crate::error::MatrixError;
+use crate::kernel::{self, BlockSize};
+use crate::matrix::Matrix;
+
+impl<T: Element> Matrix<T> {
+    /// Multiplies `self` by `m` with the blocked kernel on rayon's global pool.
+    ///
+    /// Each rayon task computes one band of `block.rows` output rows, so this
+    /// shares threads with the rest of the process instead of adding its own.
+    ///
+    /// Returns [`MatrixError::ShapeMismatch`] if `self.cols != m.rows`.
+    pub fn par_mul(&self, m: &Matrix<T>, block: BlockSize) -> Result<Matrix<T>, MatrixError> {
+        let m1 = self;
+        let m2 = m;
+
+        if m1.cols() != m2.rows() {
+            return Err(m1.shape_mismatch(m2))
+        }
+
+        let mut m = Matrix::new(m1.rows(), m2.cols(), vec![T::zero(); m1.rows() * m2.cols()])?;
+
+        if m.cols() == 0 {
+            return Ok(m)
+        }
+
+        let (depth, cols) = (m1.cols(), m2.cols());
+        let (a, b) = (m1.cells(), m2.cells());
+        let band_rows = block.rows.max(1);
+
+        m.cells_mut()
+            .par_chunks_mut(band_rows * cols)
+            .enumerate()
+            .for_each(|(band, c)| {
+                let row_start = band * band_rows;
+                let a_rows = &a[row_start * depth..(row_start + c.len() / cols) * depth];
+                kernel::blocked::<T>(a_rows, b, c, depth, cols, block);
+            });
+
+        Ok(m)
+    }
+
+    /// Like [`Matrix::par_mul`], but runs on the caller-supplied rayon `pool`.
+    pub fn par_mul_in(&self, m: &Matrix<T>, block: BlockSize, pool: &ThreadPool) -> Result<Matrix<T>, MatrixError> {
+        pool.install(|| self.par_mul(m, block))
+    }
+}
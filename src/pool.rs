@@ -0,0 +1,278 @@
+use std::env;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::element::Element;
+use crate::error::MatrixError;
+use crate::kernel;
+use crate::matrix::Matrix;
+use crate::semiring::Semiring;
+
+type Job = Box<dyn FnOnce() + Send + 'static>;
+type ScopedJob<'a> = Box<dyn FnOnce() + Send + 'a>;
+
+/// Environment variable that overrides the default thread count.
+pub const THREADS_ENV_VAR: &str = "MATMUL_THREADS";
+
+/// How many row chunks the output is cut into per job, so that jobs finishing
+/// early have spare chunks to pick up.
+const CHUNKS_PER_JOB: usize = 8;
+
+/// The work done by one job of a multithreaded multiplication.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WorkerStats {
+    /// Row chunks this job claimed.
+    pub chunks: usize,
+    /// Output rows this job computed.
+    pub rows: usize,
+    /// Time spent computing those rows.
+    pub busy: Duration,
+}
+
+/// A fixed set of worker threads that runs multiplication jobs.
+///
+/// Spawning threads for every product is expensive when many medium-sized
+/// matrices are multiplied in a loop, so the workers are started once and
+/// reused by every call. Dropping the pool waits for queued jobs to finish.
+pub struct MatMulPool {
+    sender: Option<mpsc::Sender<Job>>,
+    workers: Vec<thread::JoinHandle<()>>,
+}
+
+impl MatMulPool {
+    /// Starts a pool with `thread_count` workers, or one if `thread_count` is zero.
+    pub fn new(thread_count: usize) -> MatMulPool {
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let receiver = Arc::new(Mutex::new(receiver));
+
+        let workers = (0..thread_count.max(1))
+            .map(|_| {
+                let receiver = Arc::clone(&receiver);
+
+                thread::spawn(move || loop {
+                    let job = receiver.lock().unwrap().recv();
+                    match job {
+                        Ok(job) => job(),
+                        Err(_) => break,
+                    }
+                })
+            })
+            .collect();
+
+        MatMulPool {
+            sender: Some(sender),
+            workers,
+        }
+    }
+
+    /// The process-wide pool used by [`Matrix::mul_mt`], started on first use
+    /// with [`MatMulPool::default_thread_count`] workers.
+    pub fn global() -> &'static MatMulPool {
+        static GLOBAL: OnceLock<MatMulPool> = OnceLock::new();
+
+        GLOBAL.get_or_init(MatMulPool::default)
+    }
+
+    /// The number of threads to use when none is given explicitly.
+    ///
+    /// This is the value of the `MATMUL_THREADS` environment variable if it holds
+    /// a positive integer, and [`std::thread::available_parallelism`] otherwise.
+    pub fn default_thread_count() -> usize {
+        env::var(THREADS_ENV_VAR)
+            .ok()
+            .and_then(|value| value.trim().parse::<usize>().ok())
+            .filter(|&count| count > 0)
+            .or_else(|| thread::available_parallelism().ok().map(|count| count.get()))
+            .unwrap_or(1)
+    }
+
+    pub fn thread_count(&self) -> usize {
+        self.workers.len()
+    }
+
+    /// Multiplies `m1` by `m2`, spreading the output rows across all workers.
+    ///
+    /// The output is cut into row chunks that the workers claim one at a time,
+    /// so a slow worker does not hold up the others. Each chunk is written in
+    /// place, without any per-cell messaging.
+    ///
+    /// Returns [`MatrixError::ShapeMismatch`] if `m1.cols() != m2.rows()`.
+    pub fn mul<T: Element>(&self, m1: &Matrix<T>, m2: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        self.mul_with_threads(m1, m2, self.thread_count())
+    }
+
+    /// Like [`MatMulPool::mul`], but splits the work into `thread_count` jobs
+    /// instead of one per worker. A `thread_count` of zero is treated as one.
+    pub fn mul_with_threads<T: Element>(
+        &self,
+        m1: &Matrix<T>,
+        m2: &Matrix<T>,
+        thread_count: usize,
+    ) -> Result<Matrix<T>, MatrixError> {
+        self.mul_with_stats(m1, m2, thread_count).map(|(m, _)| m)
+    }
+
+    /// Like [`MatMulPool::mul_with_threads`], but also reports how much work each
+    /// of the `thread_count` jobs did, to check that the load was balanced.
+    ///
+    /// The stats are empty if the product has no cells to compute.
+    pub fn mul_with_stats<T: Element>(
+        &self,
+        m1: &Matrix<T>,
+        m2: &Matrix<T>,
+        thread_count: usize,
+    ) -> Result<(Matrix<T>, Vec<WorkerStats>), MatrixError> {
+        self.mul_semiring_with_stats::<T>(m1, m2, thread_count)
+    }
+
+    /// Like [`MatMulPool::mul`], but with the `+` and `*` of the semiring `S`.
+    ///
+    /// Returns [`MatrixError::ShapeMismatch`] if `m1.cols() != m2.rows()`.
+    pub fn mul_semiring<S: Semiring>(
+        &self,
+        m1: &Matrix<S::Value>,
+        m2: &Matrix<S::Value>,
+    ) -> Result<Matrix<S::Value>, MatrixError> {
+        self.mul_semiring_with_stats::<S>(m1, m2, self.thread_count()).map(|(m, _)| m)
+    }
+
+    fn mul_semiring_with_stats<S: Semiring>(
+        &self,
+        m1: &Matrix<S::Value>,
+        m2: &Matrix<S::Value>,
+        thread_count: usize,
+    ) -> Result<(Matrix<S::Value>, Vec<WorkerStats>), MatrixError> {
+        if m1.cols() != m2.rows() {
+            return Err(m1.shape_mismatch(m2))
+        }
+
+        let mut m = Matrix::new(m1.rows(), m2.cols(), vec![S::zero(); m1.rows() * m2.cols()])?;
+
+        if m.rows() == 0 || m.cols() == 0 || m1.cols() == 0 {
+            return Ok((m, Vec::new()))
+        }
+
+        let (depth, cols) = (m1.cols(), m2.cols());
+        let (a, b) = (m1.cells(), m2.cells());
+
+        let stats = self.run_chunked(m.cells_mut(), cols, thread_count, |row_start, c| {
+            let rows = c.len() / cols;
+            kernel::naive::<S>(&a[row_start * depth..(row_start + rows) * depth], b, c, depth, cols);
+        });
+
+        Ok((m, stats))
+    }
+
+    /// Runs `kernel` over the row-major `c`, whose rows are `cols` items long,
+    /// with `thread_count` jobs that pull row chunks from a shared counter until
+    /// none are left, so faster workers simply end up taking more chunks.
+    ///
+    /// `kernel` receives the index of the first row in the chunk and the chunk
+    /// itself. `cols` must not be zero. Runs no jobs and returns no stats if `c`
+    /// has no rows.
+    pub(crate) fn run_chunked<T: Send>(
+        &self,
+        c: &mut [T],
+        cols: usize,
+        thread_count: usize,
+        kernel: impl Fn(usize, &mut [T]) + Sync,
+    ) -> Vec<WorkerStats> {
+        let rows = c.len() / cols;
+        if rows == 0 {
+            return Vec::new()
+        }
+
+        let job_count = rows.min(thread_count.max(1));
+        let chunk_rows = rows.div_ceil(job_count * CHUNKS_PER_JOB);
+
+        let chunks: Vec<Mutex<Option<&mut [T]>>> = c
+            .chunks_mut(chunk_rows * cols)
+            .map(|chunk| Mutex::new(Some(chunk)))
+            .collect();
+        let next_chunk = AtomicUsize::new(0);
+
+        let mut stats = vec![WorkerStats::default(); job_count];
+
+        let jobs = stats
+            .iter_mut()
+            .map(|stats| {
+                let (chunks, next_chunk, kernel) = (&chunks, &next_chunk, &kernel);
+
+                Box::new(move || loop {
+                    let index = next_chunk.fetch_add(1, Ordering::Relaxed);
+                    let Some(chunk) = chunks.get(index) else {
+                        break
+                    };
+                    // Every index is handed out once, so the slot is always full.
+                    let c = chunk.lock().unwrap().take().unwrap();
+
+                    let started = Instant::now();
+                    let rows = c.len() / cols;
+                    kernel(index * chunk_rows, c);
+
+                    stats.busy += started.elapsed();
+                    stats.chunks += 1;
+                    stats.rows += rows;
+                }) as ScopedJob
+            })
+            .collect();
+
+        self.run_scoped(jobs);
+
+        stats
+    }
+
+    /// Runs `jobs` on the workers and blocks until every one of them has finished,
+    /// which is what lets them borrow from the caller's stack.
+    ///
+    /// Panics if any job panicked. Must not be called from inside a job, since
+    /// the calling worker would wait on itself.
+    fn run_scoped<'a>(&self, jobs: Vec<ScopedJob<'a>>) {
+        let (done_tx, done_rx) = mpsc::channel();
+        let job_count = jobs.len();
+
+        for job in jobs {
+            let done_tx = done_tx.clone();
+            let job: ScopedJob<'a> = Box::new(move || {
+                let result = panic::catch_unwind(AssertUnwindSafe(job));
+                done_tx.send(result.is_ok()).unwrap();
+            });
+
+            // SAFETY: the loop below does not return until every job has reported
+            // back, so nothing the job borrows for `'a` is released while it runs.
+            // A job that is dropped unrun never touches its borrows either.
+            let job: Job = unsafe { std::mem::transmute::<ScopedJob<'a>, Job>(job) };
+            self.sender.as_ref().unwrap().send(job).unwrap();
+        }
+
+        drop(done_tx);
+
+        let mut all_ok = true;
+        for _ in 0..job_count {
+            all_ok &= done_rx.recv().unwrap_or(false);
+        }
+
+        assert!(all_ok, "a multiplication job panicked");
+    }
+}
+
+impl Default for MatMulPool {
+    /// Starts a pool with [`MatMulPool::default_thread_count`] workers.
+    fn default() -> Self {
+        MatMulPool::new(MatMulPool::default_thread_count())
+    }
+}
+
+impl Drop for MatMulPool {
+    fn drop(&mut self) {
+        drop(self.sender.take());
+
+        for worker in self.workers.drain(..) {
+            worker.join().unwrap();
+        }
+    }
+}
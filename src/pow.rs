@@ -0,0 +1,70 @@
+use crate::element::{Element, Integer};
+use crate::error::MatrixError;
+use crate::matrix::Matrix;
+use crate::modular;
+
+impl<T: Element> Matrix<T> {
+    /// Raises a square matrix to the power `n` by repeated squaring, which takes
+    /// about `2 * log2(n)` calls to [`Matrix::mul`]. `pow(0)` is the identity.
+    ///
+    /// Returns [`MatrixError::NotSquare`] if the matrix is not square.
+    pub fn pow(&self, n: u64) -> Result<Matrix<T>, MatrixError> {
+        self.check_square()?;
+
+        let mut result = Matrix::identity(self.rows());
+        let mut base = self.clone();
+        let mut n = n;
+
+        while n > 0 {
+            if n & 1 == 1 {
+                result = result.mul(&base)?;
+            }
+            n >>= 1;
+            if n > 0 {
+                base = base.mul(&base)?;
+            }
+        }
+
+        Ok(result)
+    }
+
+    pub(crate) fn check_square(&self) -> Result<(), MatrixError> {
+        if self.rows() != self.cols() {
+            return Err(MatrixError::NotSquare { rows: self.rows(), cols: self.cols() })
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: Integer> Matrix<T> {
+    /// Like [`Matrix::pow`], but reduces every cell modulo `modulus`, so large
+    /// exponents cannot overflow. The cells of the result are in `0..modulus`.
+    ///
+    /// Every step is a [`Matrix::mul_mod`], so any modulus up to `u64::MAX` is exact.
+    ///
+    /// Returns [`MatrixError::NotSquare`] if the matrix is not square and
+    /// [`MatrixError::InvalidModulus`] unless `modulus` is in `1..=u64::MAX`.
+    pub fn pow_mod(&self, n: u64, modulus: T) -> Result<Matrix<T>, MatrixError> {
+        self.check_square()?;
+
+        let p = modular::modulus(modulus)?;
+        let size = self.rows();
+
+        let mut result = Matrix::<T>::identity(size).residues(p);
+        let mut base = self.residues(p);
+        let mut n = n;
+
+        while n > 0 {
+            if n & 1 == 1 {
+                result = modular::mul_residues(&result, &base, size, size, size, p);
+            }
+            n >>= 1;
+            if n > 0 {
+                base = modular::mul_residues(&base, &base, size, size, size, p);
+            }
+        }
+
+        Matrix::new(size, size, result.into_iter().map(T::from_residue).collect())
+    }
+}
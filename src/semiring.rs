@@ -0,0 +1,245 @@
+use std::marker::PhantomData;
+
+use crate::element::Element;
+use crate::error::MatrixError;
+use crate::kernel;
+use crate::matrix::Matrix;
+use crate::pool::MatMulPool;
+
+/// The `+` and `*` a multiplication kernel works with.
+///
+/// Every [`Element`] is a semiring with its ordinary arithmetic, which is what
+/// [`Matrix::mul`] uses. The marker types [`MinPlus`], [`MaxPlus`] and
+/// [`Boolean`] swap in other operations for [`Matrix::mul_semiring`].
+pub trait Semiring: 'static {
+    /// The type of the matrix cells.
+    type Value: Element;
+
+    /// The identity of `add`, which `mul` by anything leaves unchanged.
+    fn zero() -> Self::Value;
+
+    /// The identity of `mul`.
+    fn one() -> Self::Value;
+
+    fn add(x: Self::Value, y: Self::Value) -> Self::Value;
+
+    fn mul(x: Self::Value, y: Self::Value) -> Self::Value;
+
+    /// The `add` of the `mul` of every pair, for slices of equal length.
+    ///
+    /// This is the inner loop of the packed kernel.
+    fn dot(a: &[Self::Value], b: &[Self::Value]) -> Self::Value {
+        a.iter().zip(b).fold(Self::zero(), |acc, (&x, &y)| Self::add(acc, Self::mul(x, y)))
+    }
+
+    /// Replaces every `y` with `add(y, mul(alpha, x))`, for slices of equal length.
+    ///
+    /// This is the inner loop of the naive and blocked kernels.
+    fn axpy(alpha: Self::Value, x: &[Self::Value], y: &mut [Self::Value]) {
+        for (y, &x) in y.iter_mut().zip(x) {
+            *y = Self::add(*y, Self::mul(alpha, x));
+        }
+    }
+}
+
+impl<T: Element> Semiring for T {
+    type Value = T;
+
+    fn zero() -> T {
+        Element::zero()
+    }
+
+    fn one() -> T {
+        Element::one()
+    }
+
+    fn add(x: T, y: T) -> T {
+        Element::add(x, y)
+    }
+
+    fn mul(x: T, y: T) -> T {
+        Element::mul(x, y)
+    }
+
+    fn dot(a: &[T], b: &[T]) -> T {
+        Element::dot(a, b)
+    }
+
+    fn axpy(alpha: T, x: &[T], y: &mut [T]) {
+        Element::axpy(alpha, x, y)
+    }
+}
+
+/// Element types with values that stand for positive and negative infinity,
+/// used by the [`MinPlus`] and [`MaxPlus`] semirings.
+///
+/// Integers use their largest and smallest values, floats their infinities.
+pub trait Tropical: Element + PartialOrd {
+    fn infinity() -> Self;
+
+    fn neg_infinity() -> Self;
+
+    /// Adds two finite values, clamping integers to their range instead of
+    /// wrapping.
+    fn saturating_add(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_tropical {
+    ($($t:ty),* ; $inf:ident, $neg_inf:ident, |$x:ident, $y:ident| $add:expr) => {
+        $(
+            impl Tropical for $t {
+                fn infinity() -> Self {
+                    <$t>::$inf
+                }
+
+                fn neg_infinity() -> Self {
+                    <$t>::$neg_inf
+                }
+
+                fn saturating_add(self, rhs: Self) -> Self {
+                    let ($x, $y) = (self, rhs);
+                    $add
+                }
+            }
+        )*
+    };
+}
+
+impl_tropical!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize;
+    MAX, MIN, |x, y| x.saturating_add(y)
+);
+impl_tropical!(f32, f64; INFINITY, NEG_INFINITY, |x, y| x + y);
+
+/// The min-plus (tropical) semiring: `add` is `min` and `mul` is `+`, with
+/// [`Tropical::infinity`] as zero and `0` as one.
+///
+/// With edge weights as cells and infinity for missing edges, the `k`-th power
+/// of an adjacency matrix holds the shortest paths of at most `k` edges.
+pub struct MinPlus<T>(PhantomData<T>);
+
+impl<T: Tropical> Semiring for MinPlus<T> {
+    type Value = T;
+
+    fn zero() -> T {
+        T::infinity()
+    }
+
+    fn one() -> T {
+        Element::zero()
+    }
+
+    fn add(x: T, y: T) -> T {
+        if y < x { y } else { x }
+    }
+
+    fn mul(x: T, y: T) -> T {
+        if x == T::infinity() || y == T::infinity() {
+            return T::infinity()
+        }
+
+        x.saturating_add(y)
+    }
+}
+
+/// The max-plus semiring: `add` is `max` and `mul` is `+`, with
+/// [`Tropical::neg_infinity`] as zero and `0` as one.
+///
+/// With task durations as cells, products give the latest finishing times
+/// along the longest paths of a schedule.
+pub struct MaxPlus<T>(PhantomData<T>);
+
+impl<T: Tropical> Semiring for MaxPlus<T> {
+    type Value = T;
+
+    fn zero() -> T {
+        T::neg_infinity()
+    }
+
+    fn one() -> T {
+        Element::zero()
+    }
+
+    fn add(x: T, y: T) -> T {
+        if y > x { y } else { x }
+    }
+
+    fn mul(x: T, y: T) -> T {
+        if x == T::neg_infinity() || y == T::neg_infinity() {
+            return T::neg_infinity()
+        }
+
+        x.saturating_add(y)
+    }
+}
+
+/// The boolean semiring: `add` is OR and `mul` is AND, with any nonzero cell
+/// counting as true. Results hold only zeros and ones.
+///
+/// The `k`-th power of an adjacency matrix tells which nodes are reachable in
+/// exactly `k` steps.
+pub struct Boolean<T>(PhantomData<T>);
+
+impl<T: Element> Semiring for Boolean<T> {
+    type Value = T;
+
+    fn zero() -> T {
+        Element::zero()
+    }
+
+    fn one() -> T {
+        Element::one()
+    }
+
+    fn add(x: T, y: T) -> T {
+        truth(x != Element::zero() || y != Element::zero())
+    }
+
+    fn mul(x: T, y: T) -> T {
+        truth(x != Element::zero() && y != Element::zero())
+    }
+
+    fn axpy(alpha: T, x: &[T], y: &mut [T]) {
+        // AND with false leaves every `y` unchanged.
+        if alpha == Element::zero() {
+            return
+        }
+
+        for (y, &x) in y.iter_mut().zip(x) {
+            if x != Element::zero() {
+                *y = Element::one();
+            }
+        }
+    }
+}
+
+fn truth<T: Element>(value: bool) -> T {
+    if value { Element::one() } else { Element::zero() }
+}
+
+impl<T: Element> Matrix<T> {
+    /// Multiplies `self` by `m` on the current thread with the `+` and `*` of
+    /// the semiring `S`, using the same kernel as [`Matrix::mul`]. For example
+    /// `mul_semiring::<MinPlus<i32>>` extends shortest paths by one edge.
+    ///
+    /// Returns [`MatrixError::ShapeMismatch`] if `self.cols != m.rows`.
+    pub fn mul_semiring<S: Semiring<Value = T>>(&self, m: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        let m1 = self;
+        let m2 = m;
+
+        if m1.cols() != m2.rows() {
+            return Err(m1.shape_mismatch(m2))
+        }
+
+        let mut m = Matrix::new(m1.rows(), m2.cols(), vec![S::zero(); m1.rows() * m2.cols()])?;
+        kernel::naive::<S>(m1.cells(), m2.cells(), m.cells_mut(), m1.cols(), m2.cols());
+
+        Ok(m)
+    }
+
+    /// Like [`Matrix::mul_semiring`], but runs on the worker threads of
+    /// [`MatMulPool::global`] as [`Matrix::mul_mt`] does.
+    pub fn mul_semiring_mt<S: Semiring<Value = T>>(&self, m: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        MatMulPool::global().mul_semiring::<S>(self, m)
+    }
+}
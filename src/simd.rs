@@ -0,0 +1,174 @@
+//! SIMD versions of the inner kernels for `i32` and `f32`.
+//!
+//! Each function checks for AVX2 at runtime and falls back to a scalar loop,
+//! so the same binary runs on CPUs with and without it. `i32` lanes wrap on
+//! overflow just like the scalar path does, and wrapping addition is
+//! associative, so both paths give bit-identical `i32` results.
+
+macro_rules! dispatch {
+    ($avx2:expr, $scalar:expr) => {{
+        #[cfg(target_arch = "x86_64")]
+        {
+            if is_x86_feature_detected!("avx2") {
+                // SAFETY: AVX2 support was detected just above.
+                return unsafe { $avx2 };
+            }
+        }
+
+        $scalar
+    }};
+}
+
+pub(crate) fn dot_i32(a: &[i32], b: &[i32]) -> i32 {
+    let n = a.len().min(b.len());
+    let (a, b) = (&a[..n], &b[..n]);
+
+    dispatch!(avx2::dot_i32(a, b), scalar_dot_i32(a, b))
+}
+
+pub(crate) fn axpy_i32(alpha: i32, x: &[i32], y: &mut [i32]) {
+    let n = x.len().min(y.len());
+    let (x, y) = (&x[..n], &mut y[..n]);
+
+    dispatch!(avx2::axpy_i32(alpha, x, y), scalar_axpy_i32(alpha, x, y))
+}
+
+pub(crate) fn dot_f32(a: &[f32], b: &[f32]) -> f32 {
+    let n = a.len().min(b.len());
+    let (a, b) = (&a[..n], &b[..n]);
+
+    dispatch!(avx2::dot_f32(a, b), scalar_dot_f32(a, b))
+}
+
+pub(crate) fn axpy_f32(alpha: f32, x: &[f32], y: &mut [f32]) {
+    let n = x.len().min(y.len());
+    let (x, y) = (&x[..n], &mut y[..n]);
+
+    dispatch!(avx2::axpy_f32(alpha, x, y), scalar_axpy_f32(alpha, x, y))
+}
+
+fn scalar_dot_i32(a: &[i32], b: &[i32]) -> i32 {
+    a.iter().zip(b).fold(0, |acc: i32, (&x, &y)| acc.wrapping_add(x.wrapping_mul(y)))
+}
+
+fn scalar_axpy_i32(alpha: i32, x: &[i32], y: &mut [i32]) {
+    for (y, &x) in y.iter_mut().zip(x) {
+        *y = y.wrapping_add(alpha.wrapping_mul(x));
+    }
+}
+
+fn scalar_dot_f32(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).fold(0.0, |acc, (&x, &y)| acc + x * y)
+}
+
+fn scalar_axpy_f32(alpha: f32, x: &[f32], y: &mut [f32]) {
+    for (y, &x) in y.iter_mut().zip(x) {
+        *y += alpha * x;
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+mod avx2 {
+    use std::arch::x86_64::*;
+
+    const LANES: usize = 8;
+
+    // All functions below expect slices of equal length and must only be
+    // called once AVX2 support has been detected.
+
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn dot_i32(a: &[i32], b: &[i32]) -> i32 {
+        let chunks = a.len() / LANES;
+        let mut acc = _mm256_setzero_si256();
+
+        for i in 0..chunks {
+            let x = _mm256_loadu_si256(a.as_ptr().add(i * LANES) as *const __m256i);
+            let y = _mm256_loadu_si256(b.as_ptr().add(i * LANES) as *const __m256i);
+            acc = _mm256_add_epi32(acc, _mm256_mullo_epi32(x, y));
+        }
+
+        let mut lanes = [0i32; LANES];
+        _mm256_storeu_si256(lanes.as_mut_ptr() as *mut __m256i, acc);
+        let sum = lanes.iter().fold(0i32, |sum, &lane| sum.wrapping_add(lane));
+
+        sum.wrapping_add(super::scalar_dot_i32(&a[chunks * LANES..], &b[chunks * LANES..]))
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn axpy_i32(alpha: i32, x: &[i32], y: &mut [i32]) {
+        let chunks = x.len() / LANES;
+        let alpha_v = _mm256_set1_epi32(alpha);
+
+        for i in 0..chunks {
+            let x_v = _mm256_loadu_si256(x.as_ptr().add(i * LANES) as *const __m256i);
+            let y_ptr = y.as_mut_ptr().add(i * LANES) as *mut __m256i;
+            let y_v = _mm256_loadu_si256(y_ptr);
+            _mm256_storeu_si256(y_ptr, _mm256_add_epi32(y_v, _mm256_mullo_epi32(alpha_v, x_v)));
+        }
+
+        super::scalar_axpy_i32(alpha, &x[chunks * LANES..], &mut y[chunks * LANES..]);
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn dot_f32(a: &[f32], b: &[f32]) -> f32 {
+        let chunks = a.len() / LANES;
+        let mut acc = _mm256_setzero_ps();
+
+        for i in 0..chunks {
+            let x = _mm256_loadu_ps(a.as_ptr().add(i * LANES));
+            let y = _mm256_loadu_ps(b.as_ptr().add(i * LANES));
+            acc = _mm256_add_ps(acc, _mm256_mul_ps(x, y));
+        }
+
+        let mut lanes = [0f32; LANES];
+        _mm256_storeu_ps(lanes.as_mut_ptr(), acc);
+        let sum: f32 = lanes.iter().sum();
+
+        sum + super::scalar_dot_f32(&a[chunks * LANES..], &b[chunks * LANES..])
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn axpy_f32(alpha: f32, x: &[f32], y: &mut [f32]) {
+        let chunks = x.len() / LANES;
+        let alpha_v = _mm256_set1_ps(alpha);
+
+        for i in 0..chunks {
+            let x_v = _mm256_loadu_ps(x.as_ptr().add(i * LANES));
+            let y_ptr = y.as_mut_ptr().add(i * LANES);
+            let y_v = _mm256_loadu_ps(y_ptr);
+            _mm256_storeu_ps(y_ptr, _mm256_add_ps(y_v, _mm256_mul_ps(alpha_v, x_v)));
+        }
+
+        super::scalar_axpy_f32(alpha, &x[chunks * LANES..], &mut y[chunks * LANES..]);
+    }
+}
+
+#[cfg(all(test, target_arch = "x86_64"))]
+mod tests {
+    use super::*;
+
+    /// Values spread over the whole `i32` range, so products and sums wrap.
+    fn wrapping_values(len: usize, seed: i32) -> Vec<i32> {
+        (0..len as i32).map(|i| seed.wrapping_mul(i + 1).wrapping_mul(0x9E37_79B9u32 as i32)).collect()
+    }
+
+    #[test]
+    fn avx2_i32_matches_scalar_when_wrapping() {
+        if !is_x86_feature_detected!("avx2") {
+            return
+        }
+
+        for len in [0, 1, 7, 9, 15, 17, 63, 100] {
+            let (a, b) = (wrapping_values(len, 7), wrapping_values(len, -13));
+
+            // SAFETY: AVX2 support was detected above.
+            assert_eq!(unsafe { avx2::dot_i32(&a, &b) }, scalar_dot_i32(&a, &b), "dot, len {}", len);
+
+            let (mut y_avx2, mut y_scalar) = (b.clone(), b.clone());
+            // SAFETY: AVX2 support was detected above.
+            unsafe { avx2::axpy_i32(i32::MAX - 3, &a, &mut y_avx2) };
+            scalar_axpy_i32(i32::MAX - 3, &a, &mut y_scalar);
+            assert_eq!(y_avx2, y_scalar, "axpy, len {}", len);
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use crate::element::Element;
+use crate::kernel::{self, BlockSize};
+
+/// Computes the `rows` x `cols` product of the row-major `a` (`rows` x `depth`)
+/// and `b` (`depth` x `cols`) with Strassen's seven-multiplication scheme.
+///
+/// Every level splits both operands into quadrants, padding odd dimensions with
+/// a zero row or column, and recurses until the smallest dimension is at most
+/// `cutoff`, where the blocked kernel takes over.
+pub(crate) fn strassen<T: Element>(a: &[T], b: &[T], rows: usize, depth: usize, cols: usize, cutoff: usize) -> Vec<T> {
+    let mut c = vec![T::zero(); rows * cols];
+
+    if rows.min(depth).min(cols) <= cutoff.max(1) {
+        kernel::blocked::<T>(a, b, &mut c, depth, cols, BlockSize::default());
+        return c;
+    }
+
+    let (h_rows, h_depth, h_cols) = (rows.div_ceil(2), depth.div_ceil(2), cols.div_ceil(2));
+
+    let a11 = quadrant(a, rows, depth, 0, 0, h_rows, h_depth);
+    let a12 = quadrant(a, rows, depth, 0, h_depth, h_rows, h_depth);
+    let a21 = quadrant(a, rows, depth, h_rows, 0, h_rows, h_depth);
+    let a22 = quadrant(a, rows, depth, h_rows, h_depth, h_rows, h_depth);
+
+    let b11 = quadrant(b, depth, cols, 0, 0, h_depth, h_cols);
+    let b12 = quadrant(b, depth, cols, 0, h_cols, h_depth, h_cols);
+    let b21 = quadrant(b, depth, cols, h_depth, 0, h_depth, h_cols);
+    let b22 = quadrant(b, depth, cols, h_depth, h_cols, h_depth, h_cols);
+
+    let recurse = |x: &[T], y: &[T]| strassen(x, y, h_rows, h_depth, h_cols, cutoff);
+
+    let m1 = recurse(&add(&a11, &a22), &add(&b11, &b22));
+    let m2 = recurse(&add(&a21, &a22), &b11);
+    let m3 = recurse(&a11, &sub(&b12, &b22));
+    let m4 = recurse(&a22, &sub(&b21, &b11));
+    let m5 = recurse(&add(&a11, &a12), &b22);
+    let m6 = recurse(&sub(&a21, &a11), &add(&b11, &b12));
+    let m7 = recurse(&sub(&a12, &a22), &add(&b21, &b22));
+
+    let c11 = add(&sub(&add(&m1, &m4), &m5), &m7);
+    let c12 = add(&m3, &m5);
+    let c21 = add(&m2, &m4);
+    let c22 = add(&add(&sub(&m1, &m2), &m3), &m6);
+
+    write_quadrant(&mut c, rows, cols, 0, 0, h_rows, h_cols, &c11);
+    write_quadrant(&mut c, rows, cols, 0, h_cols, h_rows, h_cols, &c12);
+    write_quadrant(&mut c, rows, cols, h_rows, 0, h_rows, h_cols, &c21);
+    write_quadrant(&mut c, rows, cols, h_rows, h_cols, h_rows, h_cols, &c22);
+
+    c
+}
+
+/// Copies the `height` x `width` block starting at (`row`, `col`) out of `src`,
+/// filling cells that fall outside `src` with zero.
+fn quadrant<T: Element>(
+    src: &[T],
+    src_rows: usize,
+    src_cols: usize,
+    row: usize,
+    col: usize,
+    height: usize,
+    width: usize,
+) -> Vec<T> {
+    let mut dst = vec![T::zero(); height * width];
+
+    for i in 0..height.min(src_rows.saturating_sub(row)) {
+        let len = width.min(src_cols.saturating_sub(col));
+        let src_start = (row + i) * src_cols + col;
+        dst[i * width..i * width + len].copy_from_slice(&src[src_start..src_start + len]);
+    }
+
+    dst
+}
+
+/// Copies the `height` x `width` block `src` into `dst` at (`row`, `col`),
+/// dropping cells that fall outside `dst`.
+#[allow(clippy::too_many_arguments)]
+fn write_quadrant<T: Element>(
+    dst: &mut [T],
+    dst_rows: usize,
+    dst_cols: usize,
+    row: usize,
+    col: usize,
+    height: usize,
+    width: usize,
+    src: &[T],
+) {
+    for i in 0..height.min(dst_rows.saturating_sub(row)) {
+        let len = width.min(dst_cols.saturating_sub(col));
+        let dst_start = (row + i) * dst_cols + col;
+        dst[dst_start..dst_start + len].copy_from_slice(&src[i * width..i * width + len]);
+    }
+}
+
+fn add<T: Element>(x: &[T], y: &[T]) -> Vec<T> {
+    x.iter().zip(y).map(|(&x, &y)| x.add(y)).collect()
+}
+
+fn sub<T: Element>(x: &[T], y: &[T]) -> Vec<T> {
+    x.iter().zip(y).map(|(&x, &y)| x.sub(y)).collect()
+}
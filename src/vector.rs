@@ -0,0 +1,130 @@
+use crate::element::Element;
+use crate::error::MatrixError;
+use crate::matrix::Matrix;
+use crate::pool::MatMulPool;
+
+impl<T: Element> Matrix<T> {
+    /// Computes the matrix-vector product `self * x` on the current thread.
+    ///
+    /// Each output cell is one dot product of a contiguous row with `x`, so the
+    /// matrix is streamed through exactly once.
+    ///
+    /// Returns [`MatrixError::ShapeMismatch`] if `x.len() != self.cols`.
+    pub fn mul_vec(&self, x: &[T]) -> Result<Vec<T>, MatrixError> {
+        self.check_mul_vec(x)?;
+
+        let mut y = vec![T::zero(); self.rows()];
+        mul_vec_rows(self, x, &mut y, 0);
+
+        Ok(y)
+    }
+
+    /// Computes the vector-matrix product `x * self` on the current thread.
+    ///
+    /// The output is built by adding a scaled row of the matrix per cell of `x`,
+    /// so the matrix is streamed through exactly once, row by row.
+    ///
+    /// Returns [`MatrixError::ShapeMismatch`] if `x.len() != self.rows`.
+    pub fn vec_mul(&self, x: &[T]) -> Result<Vec<T>, MatrixError> {
+        self.check_vec_mul(x)?;
+
+        let mut y = vec![T::zero(); self.cols()];
+        vec_mul_rows(self, x, &mut y, 0);
+
+        Ok(y)
+    }
+
+    /// Like [`Matrix::mul_vec`], but runs on the worker threads of [`MatMulPool::global`].
+    pub fn mul_vec_mt(&self, x: &[T]) -> Result<Vec<T>, MatrixError> {
+        MatMulPool::global().mul_vec(self, x)
+    }
+
+    /// Like [`Matrix::vec_mul`], but runs on the worker threads of [`MatMulPool::global`].
+    pub fn vec_mul_mt(&self, x: &[T]) -> Result<Vec<T>, MatrixError> {
+        MatMulPool::global().vec_mul(self, x)
+    }
+
+    fn check_mul_vec(&self, x: &[T]) -> Result<(), MatrixError> {
+        if x.len() != self.cols() {
+            return Err(MatrixError::ShapeMismatch { left: self.shape(), right: (x.len(), 1) })
+        }
+
+        Ok(())
+    }
+
+    fn check_vec_mul(&self, x: &[T]) -> Result<(), MatrixError> {
+        if x.len() != self.rows() {
+            return Err(MatrixError::ShapeMismatch { left: (1, x.len()), right: self.shape() })
+        }
+
+        Ok(())
+    }
+}
+
+impl MatMulPool {
+    /// Like [`Matrix::mul_vec`], but spreads the output rows across the workers.
+    pub fn mul_vec<T: Element>(&self, m: &Matrix<T>, x: &[T]) -> Result<Vec<T>, MatrixError> {
+        m.check_mul_vec(x)?;
+
+        let mut y = vec![T::zero(); m.rows()];
+        if !y.is_empty() {
+            self.run_chunked(&mut y, 1, self.thread_count(), |row_start, y| mul_vec_rows(m, x, y, row_start));
+        }
+
+        Ok(y)
+    }
+
+    /// Like [`Matrix::vec_mul`], but splits the rows of the matrix into one
+    /// contiguous band per worker. Each worker streams its band into a partial
+    /// output of its own, and the partial outputs are summed at the end, so the
+    /// matrix is still read exactly once.
+    pub fn vec_mul<T: Element>(&self, m: &Matrix<T>, x: &[T]) -> Result<Vec<T>, MatrixError> {
+        m.check_vec_mul(x)?;
+
+        let (rows, cols) = m.shape();
+        let mut y = vec![T::zero(); cols];
+        if rows == 0 || cols == 0 {
+            return Ok(y)
+        }
+
+        let band_rows = rows.div_ceil(rows.min(self.thread_count()));
+        let mut partials = vec![T::zero(); rows.div_ceil(band_rows) * cols];
+
+        self.run_chunked(&mut partials, cols, self.thread_count(), |band_start, partials| {
+            for (band, partial) in partials.chunks_exact_mut(cols).enumerate() {
+                let row_start = (band_start + band) * band_rows;
+                let row_end = (row_start + band_rows).min(rows);
+                vec_mul_rows(m, &x[row_start..row_end], partial, row_start);
+            }
+        });
+
+        for partial in partials.chunks_exact(cols) {
+            for (y, &p) in y.iter_mut().zip(partial) {
+                *y = y.add(p);
+            }
+        }
+
+        Ok(y)
+    }
+}
+
+/// Fills `y` with rows `row_start..row_start + y.len()` of `m * x`.
+fn mul_vec_rows<T: Element>(m: &Matrix<T>, x: &[T], y: &mut [T], row_start: usize) {
+    let cols = m.cols();
+
+    for (i, y_cell) in y.iter_mut().enumerate() {
+        let row = row_start + i;
+        *y_cell = T::dot(&m.cells()[row * cols..(row + 1) * cols], x);
+    }
+}
+
+/// Adds rows `row_start..row_start + x.len()` of `m`, scaled by the matching
+/// cells of `x`, to `y`.
+fn vec_mul_rows<T: Element>(m: &Matrix<T>, x: &[T], y: &mut [T], row_start: usize) {
+    let cols = m.cols();
+
+    for (i, &x_cell) in x.iter().enumerate() {
+        let row = row_start + i;
+        T::axpy(x_cell, &m.cells()[row * cols..(row + 1) * cols], y);
+    }
+}
@@ -0,0 +1,696 @@
+use matrix_multiplication::{
+    gemm, gemm_mt, BatchShape, BitMatrix, BlockSize, Boolean, ChainOrder, ChainPlan, Kernel, MatMulPool, Matrix, MatrixError,
+    MaxPlus, MinPlus, Overflow, Transpose,
+};
+
+fn matrix(rows: usize, cols: usize, cells: Vec<i32>) -> Matrix<i32> {
+    Matrix::new(rows, cols, cells).unwrap()
+}
+
+#[test]
+fn get_and_set_use_row_col() {
+    let mut m = matrix(2, 3, vec![1, 2, 3, 4, 5, 6]);
+
+    assert_eq!(m.shape(), (2, 3));
+    assert_eq!(m.get(0, 2), Ok(3));
+    assert_eq!(m.get(1, 0), Ok(4));
+    assert_eq!(m.set(1, 2, 60), Ok(6));
+    assert_eq!(m.cells(), &[1, 2, 3, 4, 5, 60]);
+}
+
+#[test]
+fn get_and_set_reject_out_of_bounds() {
+    let mut m = matrix(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    let err = MatrixError::IndexOutOfBounds { row: 2, col: 0, rows: 2, cols: 3 };
+
+    assert_eq!(m.get(2, 0), Err(err.clone()));
+    assert_eq!(m.set(2, 0, 7), Err(err));
+    assert!(m.get(0, 3).is_err());
+    assert!(m.get(1, 2).is_ok());
+}
+
+#[test]
+fn new_rejects_bad_cell_count() {
+    let m = Matrix::new(2, 3, vec![1, 2, 3, 4, 5]);
+
+    assert_eq!(m.err(), Some(MatrixError::BadCellCount { expected: 6, got: 5 }));
+}
+
+#[test]
+fn mul_wide_by_tall() {
+    let a = matrix(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    let b = matrix(3, 2, vec![7, 8, 9, 10, 11, 12]);
+
+    let c = a.mul(&b).unwrap();
+    assert_eq!(c.shape(), (2, 2));
+    assert_eq!(c.cells(), &[58, 64, 139, 154]);
+
+    let c = a.mul_mt(&b).unwrap();
+    assert_eq!(c.cells(), &[58, 64, 139, 154]);
+}
+
+#[test]
+fn mul_tall_by_wide() {
+    let a = matrix(3, 2, vec![7, 8, 9, 10, 11, 12]);
+    let b = matrix(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    let expected = [39, 54, 69, 49, 68, 87, 59, 82, 105];
+
+    let c = a.mul(&b).unwrap();
+    assert_eq!(c.shape(), (3, 3));
+    assert_eq!(c.cells(), &expected);
+
+    let c = a.mul_mt(&b).unwrap();
+    assert_eq!(c.cells(), &expected);
+}
+
+#[test]
+fn mul_row_by_matrix() {
+    let a = matrix(1, 2, vec![1, -1]);
+    let b = matrix(2, 4, vec![1, 2, 3, 4, 5, 6, 7, 8]);
+    let expected = [-4, -4, -4, -4];
+
+    let c = a.mul(&b).unwrap();
+    assert_eq!(c.shape(), (1, 4));
+    assert_eq!(c.cells(), &expected);
+
+    let c = a.mul_mt(&b).unwrap();
+    assert_eq!(c.cells(), &expected);
+}
+
+#[test]
+fn mul_rejects_shape_mismatch() {
+    let a = matrix(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    let b = matrix(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    let err = MatrixError::ShapeMismatch { left: (2, 3), right: (2, 3) };
+
+    assert_eq!(a.mul(&b).err(), Some(err.clone()));
+    assert_eq!(a.mul_mt(&b).err(), Some(err));
+}
+
+#[test]
+fn mul_mt_matches_mul() {
+    let a = matrix_multiplication::generate_matrix(37, 19);
+    let b = matrix_multiplication::generate_matrix(19, 53);
+
+    let expected = a.mul(&b).unwrap();
+    let c = a.mul_mt(&b).unwrap();
+
+    assert_eq!(c.shape(), (37, 53));
+    assert_eq!(c.cells(), expected.cells());
+}
+
+#[test]
+fn mul_blocked_matches_mul() {
+    let a = matrix_multiplication::generate_matrix(37, 19);
+    let b = matrix_multiplication::generate_matrix(19, 53);
+    let expected = a.mul(&b).unwrap();
+
+    for block in [
+        BlockSize::default(),
+        BlockSize { rows: 4, cols: 8, depth: 5 },
+        BlockSize { rows: 1, cols: 1, depth: 1 },
+        BlockSize { rows: 0, cols: 0, depth: 0 },
+    ] {
+        let c = a.mul_blocked(&b, block).unwrap();
+        assert_eq!(c.shape(), (37, 53));
+        assert_eq!(c.cells(), expected.cells());
+    }
+}
+
+#[test]
+fn mul_blocked_hand_computed() {
+    let a = matrix(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    let b = matrix(3, 2, vec![7, 8, 9, 10, 11, 12]);
+    let block = BlockSize { rows: 1, cols: 1, depth: 2 };
+
+    let c = a.mul_blocked(&b, block).unwrap();
+    assert_eq!(c.cells(), &[58, 64, 139, 154]);
+}
+
+#[test]
+fn mul_with_every_kernel_matches_mul() {
+    let a = matrix_multiplication::generate_matrix(23, 41);
+    let b = matrix_multiplication::generate_matrix(41, 17);
+    let expected = a.mul(&b).unwrap();
+
+    for kernel in [
+        Kernel::Naive,
+        Kernel::Blocked(BlockSize { rows: 3, cols: 5, depth: 7 }),
+        Kernel::Packed,
+    ] {
+        let c = a.mul_with(&b, kernel).unwrap();
+        assert_eq!(c.cells(), expected.cells(), "{:?}", kernel);
+    }
+}
+
+#[test]
+fn mul_with_empty_inner_dimension() {
+    let a = matrix(2, 0, vec![]);
+    let b = matrix(0, 3, vec![]);
+
+    for kernel in [Kernel::Naive, Kernel::Blocked(BlockSize::default()), Kernel::Packed] {
+        let c = a.mul_with(&b, kernel).unwrap();
+        assert_eq!(c.cells(), &[0; 6]);
+    }
+}
+
+#[test]
+fn mul_f32_and_i64_match_i32() {
+    let a = matrix_multiplication::generate_matrix(13, 29);
+    let b = matrix_multiplication::generate_matrix(29, 11);
+    let expected = a.mul_with(&b, Kernel::Packed).unwrap();
+
+    let to_f32 = |m: &Matrix<i32>| Matrix::new(m.rows(), m.cols(), m.cells().iter().map(|&c| c as f32).collect()).unwrap();
+    let to_i64 = |m: &Matrix<i32>| Matrix::new(m.rows(), m.cols(), m.cells().iter().map(|&c| c as i64).collect()).unwrap();
+
+    for kernel in [Kernel::Naive, Kernel::Blocked(BlockSize::default()), Kernel::Packed] {
+        let c = to_f32(&a).mul_with(&to_f32(&b), kernel).unwrap();
+        assert_eq!(c.cells(), to_f32(&expected).cells(), "{:?}", kernel);
+
+        let c = to_i64(&a).mul_with(&to_i64(&b), kernel).unwrap();
+        assert_eq!(c.cells(), to_i64(&expected).cells(), "{:?}", kernel);
+    }
+}
+
+#[test]
+fn mul_strassen_matches_mul() {
+    for (rows, depth, cols) in [(16, 16, 16), (37, 19, 53), (33, 65, 31), (1, 40, 9)] {
+        let a = matrix_multiplication::generate_matrix(rows, depth);
+        let b = matrix_multiplication::generate_matrix(depth, cols);
+        let expected = a.mul(&b).unwrap();
+
+        for cutoff in [0, 1, 4, 64] {
+            let c = a.mul_strassen(&b, cutoff).unwrap();
+            assert_eq!(c.shape(), (rows, cols));
+            assert_eq!(c.cells(), expected.cells(), "{}x{}x{} cutoff {}", rows, depth, cols, cutoff);
+        }
+    }
+}
+
+#[test]
+fn mul_strassen_rejects_shape_mismatch() {
+    let a = matrix(2, 3, vec![1, 2, 3, 4, 5, 6]);
+
+    assert!(a.mul_strassen(&a, 1).is_err());
+}
+
+#[test]
+fn pool_is_reused_across_products() {
+    let pool = MatMulPool::new(3);
+    assert_eq!(pool.thread_count(), 3);
+
+    for _ in 0..20 {
+        let a = matrix_multiplication::generate_matrix(9, 14);
+        let b = matrix_multiplication::generate_matrix(14, 7);
+        let expected = a.mul(&b).unwrap();
+
+        let c = pool.mul(&a, &b).unwrap();
+        assert_eq!(c.cells(), expected.cells());
+    }
+}
+
+#[test]
+fn mul_mt_with_any_thread_count_matches_mul() {
+    let a = matrix_multiplication::generate_matrix(11, 6);
+    let b = matrix_multiplication::generate_matrix(6, 5);
+    let expected = a.mul(&b).unwrap();
+
+    for thread_count in [0, 1, 2, 7, 11, 64] {
+        let c = a.mul_mt_with_threads(&b, thread_count).unwrap();
+        assert_eq!(c.cells(), expected.cells(), "{} threads", thread_count);
+    }
+}
+
+#[test]
+fn mul_mt_handles_degenerate_shapes() {
+    for (rows, depth, cols) in [(0, 3, 4), (3, 0, 4), (3, 4, 0), (0, 0, 0)] {
+        let a = Matrix::new(rows, depth, vec![1; rows * depth]).unwrap();
+        let b = Matrix::new(depth, cols, vec![1; depth * cols]).unwrap();
+
+        let c = a.mul_mt(&b).unwrap();
+        assert_eq!(c.shape(), (rows, cols));
+        assert!(c.cells().iter().all(|&cell| cell == 0));
+    }
+}
+
+#[test]
+fn default_thread_count_is_positive() {
+    assert!(MatMulPool::default_thread_count() >= 1);
+    assert!(MatMulPool::global().thread_count() >= 1);
+}
+
+#[test]
+fn mul_with_stats_covers_every_row() {
+    let pool = MatMulPool::new(4);
+    let a = matrix_multiplication::generate_matrix(101, 8);
+    let b = matrix_multiplication::generate_matrix(8, 9);
+    let expected = a.mul(&b).unwrap();
+
+    let (c, stats) = pool.mul_with_stats(&a, &b, 4).unwrap();
+    assert_eq!(c.cells(), expected.cells());
+    assert_eq!(stats.len(), 4);
+    assert_eq!(stats.iter().map(|s| s.rows).sum::<usize>(), 101);
+    assert!(stats.iter().map(|s| s.chunks).sum::<usize>() >= 4);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn par_mul_matches_mul() {
+    let a = matrix_multiplication::generate_matrix(45, 30);
+    let b = matrix_multiplication::generate_matrix(30, 21);
+    let expected = a.mul(&b).unwrap();
+    let block = BlockSize { rows: 4, cols: 8, depth: 16 };
+
+    let c = a.par_mul(&b, block).unwrap();
+    assert_eq!(c.cells(), expected.cells());
+
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(2).build().unwrap();
+    let c = a.par_mul_in(&b, block, &pool).unwrap();
+    assert_eq!(c.cells(), expected.cells());
+}
+
+#[test]
+fn operators_match_checked_methods() {
+    let a = matrix(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    let b = matrix(3, 2, vec![7, 8, 9, 10, 11, 12]);
+    let c = matrix(2, 2, vec![1, 1, 1, 1]);
+
+    assert_eq!((&a * &b + &c).cells(), &[59, 65, 140, 155]);
+    assert_eq!((a.clone() * b.clone() - c.clone()).cells(), &[57, 63, 138, 153]);
+    assert_eq!((-&c).cells(), &[-1, -1, -1, -1]);
+    assert_eq!((-matrix(1, 2, vec![i32::MIN, 0])).cells(), &[i32::MIN, 0]);
+
+    let zero = Matrix::new(1, 1, vec![0.0f64]).unwrap();
+    assert!((-&zero).cells()[0].is_sign_negative());
+    assert_eq!((&c * 3).cells(), &[3, 3, 3, 3]);
+    assert_eq!((2 * &c).cells(), &[2, 2, 2, 2]);
+
+    let mut d = c.clone();
+    d += &c;
+    d *= &c;
+    d *= 2;
+    assert_eq!(d.cells(), &[8, 8, 8, 8]);
+
+    assert_eq!(a.checked_add(&b).err(), Some(MatrixError::ShapeMismatch { left: (2, 3), right: (3, 2) }));
+}
+
+#[test]
+fn index_uses_row_col() {
+    let mut m = matrix(2, 3, vec![1, 2, 3, 4, 5, 6]);
+
+    assert_eq!(m[(1, 0)], 4);
+    m[(0, 2)] = 30;
+    assert_eq!(m.get(0, 2), Ok(30));
+}
+
+#[test]
+#[should_panic(expected = "shape mismatch between a 2x3 matrix and a 2x3 matrix")]
+fn mul_operator_panics_on_shape_mismatch() {
+    let a = matrix(2, 3, vec![1, 2, 3, 4, 5, 6]);
+
+    let _ = &a * &a;
+}
+
+#[test]
+#[should_panic(expected = "index (2, 0) out of bounds for a 2x3 matrix")]
+fn index_panics_out_of_bounds() {
+    let m = matrix(2, 3, vec![1, 2, 3, 4, 5, 6]);
+
+    let _ = m[(2, 0)];
+}
+
+#[test]
+fn mul_overflow_policies() {
+    let a = matrix(1, 2, vec![i32::MAX, 2]);
+    let b = matrix(2, 2, vec![1, 1, 1, -1]);
+
+    assert_eq!(a.mul(&b).unwrap().cells(), &[i32::MIN + 1, i32::MAX - 2]);
+    assert_eq!(a.mul_overflow(&b, Overflow::Wrapping).unwrap().cells(), &[i32::MIN + 1, i32::MAX - 2]);
+    assert_eq!(a.mul_overflow(&b, Overflow::Saturating).unwrap().cells(), &[i32::MAX, i32::MAX - 2]);
+    assert_eq!(a.mul_overflow(&b, Overflow::Checked).err(), Some(MatrixError::Overflow { row: 0, col: 0 }));
+
+    let wide = a.mul_widening::<i64>(&b).unwrap();
+    assert_eq!(wide.cells(), &[i32::MAX as i64 + 2, i32::MAX as i64 - 2]);
+}
+
+#[test]
+fn mul_overflow_checked_matches_mul_when_in_range() {
+    let a = matrix_multiplication::generate_matrix(12, 9);
+    let b = matrix_multiplication::generate_matrix(9, 14);
+
+    let c = a.mul_overflow(&b, Overflow::Checked).unwrap();
+    assert_eq!(c.cells(), a.mul(&b).unwrap().cells());
+}
+
+fn transposed(m: &Matrix<i32>) -> Matrix<i32> {
+    let mut t = Matrix::zeros(m.cols(), m.rows());
+    for i in 0..m.rows() {
+        for j in 0..m.cols() {
+            t[(j, i)] = m[(i, j)];
+        }
+    }
+    t
+}
+
+#[test]
+fn gemm_matches_operators_for_every_transpose() {
+    let a = matrix_multiplication::generate_matrix(7, 5);
+    let b = matrix_multiplication::generate_matrix(5, 9);
+    let c0 = matrix_multiplication::generate_matrix(7, 9);
+    let expected = &(&a * &b) * 3 + &c0 * -2;
+
+    for (trans_a, trans_b) in [
+        (Transpose::No, Transpose::No),
+        (Transpose::Yes, Transpose::No),
+        (Transpose::No, Transpose::Yes),
+        (Transpose::Yes, Transpose::Yes),
+    ] {
+        let a = if trans_a == Transpose::Yes { transposed(&a) } else { a.clone() };
+        let b = if trans_b == Transpose::Yes { transposed(&b) } else { b.clone() };
+
+        let mut c = c0.clone();
+        gemm(3, &a, trans_a, &b, trans_b, -2, &mut c).unwrap();
+        assert_eq!(c.cells(), expected.cells(), "{:?} {:?}", trans_a, trans_b);
+
+        let mut c = c0.clone();
+        MatMulPool::new(3).gemm(3, &a, trans_a, &b, trans_b, -2, &mut c).unwrap();
+        assert_eq!(c.cells(), expected.cells(), "{:?} {:?}", trans_a, trans_b);
+    }
+}
+
+#[test]
+fn gemm_ignores_c_when_beta_is_zero() {
+    let a = Matrix::new(1, 2, vec![1.0, 2.0]).unwrap();
+    let b = Matrix::new(2, 1, vec![3.0, 4.0]).unwrap();
+    let mut c = Matrix::new(1, 1, vec![f64::NAN]).unwrap();
+
+    gemm_mt(1.0, &a, Transpose::No, &b, Transpose::No, 0.0, &mut c).unwrap();
+    assert_eq!(c.cells(), &[11.0]);
+}
+
+#[test]
+fn gemm_handles_degenerate_shapes() {
+    for (rows, depth, cols) in [(0, 3, 4), (3, 0, 4), (3, 4, 0), (0, 0, 0)] {
+        let a = Matrix::new(rows, depth, vec![1; rows * depth]).unwrap();
+        let b = Matrix::new(depth, cols, vec![1; depth * cols]).unwrap();
+        let expected = vec![3 + 2 * depth as i32; rows * cols];
+
+        let mut c = Matrix::new(rows, cols, vec![1; rows * cols]).unwrap();
+        gemm(2, &a, Transpose::No, &b, Transpose::No, 3, &mut c).unwrap();
+        assert_eq!(c.cells(), &expected[..]);
+
+        let mut c = Matrix::new(rows, cols, vec![1; rows * cols]).unwrap();
+        gemm_mt(2, &a, Transpose::No, &b, Transpose::No, 3, &mut c).unwrap();
+        assert_eq!(c.cells(), &expected[..]);
+    }
+}
+
+#[test]
+fn gemm_rejects_wrong_output_shape() {
+    let a = matrix(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    let mut c = matrix(2, 3, vec![0; 6]);
+
+    let err = gemm(1, &a, Transpose::No, &a, Transpose::Yes, 0, &mut c).err();
+    assert_eq!(err, Some(MatrixError::ShapeMismatch { left: (2, 2), right: (2, 3) }));
+}
+
+#[test]
+fn mul_vec_and_vec_mul_match_mul() {
+    let m = matrix_multiplication::generate_matrix(23, 17);
+    let x_col = matrix_multiplication::generate_matrix(17, 1);
+    let x_row = matrix_multiplication::generate_matrix(1, 23);
+
+    let expected = m.mul(&x_col).unwrap();
+    assert_eq!(m.mul_vec(x_col.cells()).unwrap(), expected.cells());
+    assert_eq!(m.mul_vec_mt(x_col.cells()).unwrap(), expected.cells());
+
+    let expected = x_row.mul(&m).unwrap();
+    assert_eq!(m.vec_mul(x_row.cells()).unwrap(), expected.cells());
+    assert_eq!(MatMulPool::new(4).vec_mul(&m, x_row.cells()).unwrap(), expected.cells());
+}
+
+#[test]
+fn vec_mul_mt_splits_tall_matrices() {
+    let m = matrix_multiplication::generate_matrix(2003, 5);
+    let x = matrix_multiplication::generate_matrix(1, 2003);
+    let expected = m.vec_mul(x.cells()).unwrap();
+
+    for thread_count in [1, 3, 8] {
+        assert_eq!(MatMulPool::new(thread_count).vec_mul(&m, x.cells()).unwrap(), expected);
+    }
+    assert_eq!(m.vec_mul_mt(x.cells()).unwrap(), expected);
+    assert_eq!(matrix(0, 4, vec![]).vec_mul_mt(&[]).unwrap(), vec![0; 4]);
+}
+
+#[test]
+fn mul_vec_rejects_wrong_length() {
+    let m = matrix(2, 3, vec![1, 2, 3, 4, 5, 6]);
+
+    assert_eq!(m.mul_vec(&[1, 2]).err(), Some(MatrixError::ShapeMismatch { left: (2, 3), right: (2, 1) }));
+    assert_eq!(m.vec_mul_mt(&[1, 2, 3]).err(), Some(MatrixError::ShapeMismatch { left: (1, 3), right: (2, 3) }));
+    assert_eq!(m.vec_mul(&[1, 1]).unwrap(), vec![5, 7, 9]);
+}
+
+#[test]
+fn batch_mul_matches_mul() {
+    let a: Vec<_> = (0..50).map(|i| matrix_multiplication::generate_matrix(4 + i % 3, 5)).collect();
+    let b: Vec<_> = (0..50).map(|i| matrix_multiplication::generate_matrix(5, 6 + i % 4)).collect();
+
+    let c = Matrix::batch_mul(&a, &b).unwrap();
+    assert_eq!(c.len(), 50);
+    for ((a, b), c) in a.iter().zip(&b).zip(&c) {
+        assert_eq!(c.cells(), a.mul(b).unwrap().cells());
+    }
+
+    assert_eq!(
+        Matrix::batch_mul(&a, &b[1..]).err(),
+        Some(MatrixError::BatchLengthMismatch { left: 50, right: 49 })
+    );
+    assert!(Matrix::batch_mul(&b, &b).is_err());
+}
+
+#[test]
+fn batch_mul_strided_matches_mul() {
+    let shape = BatchShape { count: 30, rows: 3, depth: 4, cols: 2 };
+    let a: Vec<_> = (0..30).map(|_| matrix_multiplication::generate_matrix(3, 4)).collect();
+    let b: Vec<_> = (0..30).map(|_| matrix_multiplication::generate_matrix(4, 2)).collect();
+    let a_flat: Vec<i32> = a.iter().flat_map(|m| m.cells().to_vec()).collect();
+    let b_flat: Vec<i32> = b.iter().flat_map(|m| m.cells().to_vec()).collect();
+    let mut c_flat = vec![7; 30 * 6];
+
+    MatMulPool::new(3).batch_mul_strided(&a_flat, &b_flat, &mut c_flat, shape).unwrap();
+    for (i, c) in c_flat.chunks(6).enumerate() {
+        assert_eq!(c, a[i].mul(&b[i]).unwrap().cells());
+    }
+
+    let err = MatMulPool::global().batch_mul_strided(&a_flat, &b_flat, &mut c_flat[1..], shape).err();
+    assert_eq!(err, Some(MatrixError::BadCellCount { expected: 180, got: 179 }));
+
+    let empty = BatchShape { count: 0, rows: 2, depth: 2, cols: 2 };
+    MatMulPool::new(3).batch_mul_strided::<i32>(&[], &[], &mut [], empty).unwrap();
+    assert!(MatMulPool::new(3).batch_mul(&Vec::<Matrix<i32>>::new(), &[]).unwrap().is_empty());
+}
+
+#[test]
+fn chain_plan_picks_cheapest_order() {
+    let plan = ChainPlan::new(&[(10, 30), (30, 5), (5, 60)]).unwrap();
+    assert_eq!(plan.order.to_string(), "((A0 A1) A2)");
+    assert_eq!(plan.flops, 2 * 4500);
+
+    let plan = ChainPlan::new(&[(40, 20), (20, 30), (30, 10), (10, 30)]).unwrap();
+    assert_eq!(plan.order.to_string(), "((A0 (A1 A2)) A3)");
+    assert_eq!(plan.flops, 2 * 26000);
+
+    let plan = ChainPlan::new(&[(3, 4)]).unwrap();
+    assert_eq!(plan.order, ChainOrder::Matrix(0));
+    assert_eq!(plan.flops, 0);
+
+    // Multiplying A0 A1 first would take 2^133 operations, which saturates.
+    let huge = 1 << 44;
+    let plan = ChainPlan::new(&[(huge, huge), (huge, huge), (huge, 1)]).unwrap();
+    assert_eq!(plan.order.to_string(), "(A0 (A1 A2))");
+    assert_eq!(plan.flops, 1 << 90);
+
+    let plan = ChainPlan::new(&[(usize::MAX, usize::MAX); 3]).unwrap();
+    assert_eq!(plan.flops, u128::MAX);
+
+    assert_eq!(ChainPlan::new(&[]).err(), Some(MatrixError::EmptyChain));
+    assert_eq!(
+        ChainPlan::new(&[(2, 3), (4, 5)]).err(),
+        Some(MatrixError::ShapeMismatch { left: (2, 3), right: (4, 5) })
+    );
+}
+
+#[test]
+fn chain_mul_matches_left_to_right_product() {
+    let shapes = [(6, 13), (13, 2), (2, 9), (9, 11), (11, 3)];
+    let chain: Vec<_> = shapes.iter().map(|&(r, c)| matrix_multiplication::generate_matrix(r, c)).collect();
+
+    let mut expected = chain[0].clone();
+    for m in &chain[1..] {
+        expected = expected.mul(m).unwrap();
+    }
+
+    assert_eq!(Matrix::chain_mul(&chain).unwrap().cells(), expected.cells());
+    assert_eq!(Matrix::chain_mul_mt(&chain).unwrap().cells(), expected.cells());
+    assert_eq!(Matrix::chain_mul(&chain[..1]).unwrap().cells(), chain[0].cells());
+}
+
+#[test]
+fn pow_computes_fibonacci() {
+    let fib = matrix(2, 2, vec![1, 1, 1, 0]);
+
+    assert_eq!(fib.pow(0).unwrap().cells(), &[1, 0, 0, 1]);
+    assert_eq!(fib.pow(1).unwrap().cells(), fib.cells());
+    assert_eq!(fib.pow(10).unwrap().cells(), &[89, 55, 55, 34]);
+
+    let a = matrix_multiplication::generate_matrix(4, 4);
+    assert_eq!(a.pow(5).unwrap().cells(), (&(&(&(&a * &a) * &a) * &a) * &a).cells());
+
+    assert_eq!(matrix(2, 3, vec![0; 6]).pow(2).err(), Some(MatrixError::NotSquare { rows: 2, cols: 3 }));
+}
+
+#[test]
+fn pow_mod_handles_large_exponents() {
+    let fib = matrix(2, 2, vec![1, 1, 1, 0]);
+
+    // F(90) = 2880067194370816120 and F(91) = 4660046610375530309.
+    let m = fib.pow_mod(90, 1_000_000_007).unwrap();
+    assert_eq!(m.get(0, 1), Ok((2880067194370816120u64 % 1_000_000_007) as i32));
+    assert_eq!(m.get(0, 0), Ok((4660046610375530309u64 % 1_000_000_007) as i32));
+
+    let m = matrix(1, 1, vec![-3]).pow_mod(3, 10).unwrap();
+    assert_eq!(m.cells(), &[3]);
+
+    assert_eq!(fib.pow_mod(5, 0).err(), Some(MatrixError::InvalidModulus));
+    assert_eq!(fib.pow_mod(5, -7).err(), Some(MatrixError::InvalidModulus));
+}
+
+#[test]
+fn mul_mod_matches_reduced_product() {
+    let m1 = matrix(2, 3, vec![1, -2, 3, 40, 5, -6]);
+    let m2 = matrix(3, 2, vec![7, 8, -9, 10, 11, 12]);
+    let expected: Vec<i32> = m1.mul(&m2).unwrap().cells().iter().map(|cell| cell.rem_euclid(13)).collect();
+
+    assert_eq!(m1.mul_mod(&m2, 13).unwrap().cells(), &expected[..]);
+    assert_eq!(m1.mul_mod_mt(&m2, 13).unwrap().cells(), &expected[..]);
+    assert_eq!(MatMulPool::new(3).mul_mod(&m1, &m2, 13).unwrap().cells(), &expected[..]);
+
+    // Near `u64::MAX` every product needs the full `u128` accumulator.
+    let p = u64::MAX - 58;
+    let big = Matrix::new(1, 2, vec![p - 1, p - 1]).unwrap();
+    let col = Matrix::new(2, 1, vec![p - 1, p - 1]).unwrap();
+    assert_eq!(big.mul_mod(&col, p).unwrap().cells(), &[2]);
+
+    assert_eq!(m1.mul_mod(&m1, 13).err(), Some(MatrixError::ShapeMismatch { left: (2, 3), right: (2, 3) }));
+    assert_eq!(m1.mul_mod(&m2, 0).err(), Some(MatrixError::InvalidModulus));
+
+    for (rows, depth, cols) in [(0, 3, 4), (3, 0, 4), (3, 4, 0)] {
+        let a = Matrix::new(rows, depth, vec![1; rows * depth]).unwrap();
+        let b = Matrix::new(depth, cols, vec![1; depth * cols]).unwrap();
+        assert_eq!(a.mul_mod_mt(&b, 13).unwrap().cells(), a.mul_mod(&b, 13).unwrap().cells());
+    }
+}
+
+#[test]
+fn det_mod_and_inverse_mod() {
+    let m = matrix(3, 3, vec![2, 3, 1, 1, 4, -1, 0, 5, 2]);
+    let p = 1_000_000_007;
+
+    // det = 2 * 13 - 3 * 2 + 1 * 5 = 25.
+    assert_eq!(m.det_mod(p), Ok(25));
+    assert_eq!(matrix(2, 2, vec![0, 1, 1, 0]).det_mod(7), Ok(6));
+
+    let inverse = m.inverse_mod(p).unwrap();
+    assert_eq!(m.mul_mod(&inverse, p).unwrap().cells(), Matrix::<i32>::identity(3).cells());
+
+    let singular = matrix(2, 2, vec![1, 2, 2, 4]);
+    assert_eq!(singular.det_mod(p), Ok(0));
+    assert_eq!(singular.inverse_mod(p).err(), Some(MatrixError::Singular));
+    // 25 is a multiple of 5, so `m` has no inverse modulo 5.
+    assert_eq!(m.inverse_mod(5).err(), Some(MatrixError::Singular));
+    assert_eq!(m.inverse_mod(0).err(), Some(MatrixError::InvalidModulus));
+    assert_eq!(matrix(2, 3, vec![0; 6]).det_mod(7).err(), Some(MatrixError::NotSquare { rows: 2, cols: 3 }));
+}
+
+#[test]
+fn min_plus_finds_shortest_paths() {
+    let inf = i32::MAX;
+    // 0 -> 1 (4), 0 -> 2 (1), 2 -> 1 (2), 1 -> 3 (5).
+    let graph = matrix(4, 4, vec![0, 4, 1, inf, inf, 0, inf, 5, inf, 2, 0, inf, inf, inf, inf, 0]);
+
+    let mut distances = graph.clone();
+    for _ in 0..2 {
+        distances = distances.mul_semiring::<MinPlus<i32>>(&graph).unwrap();
+    }
+    assert_eq!(distances.cells(), &[0, 3, 1, 8, inf, 0, inf, 5, inf, 2, 0, 7, inf, inf, inf, 0]);
+
+    let squared = graph.mul_semiring::<MinPlus<i32>>(&graph).unwrap();
+    assert_eq!(graph.mul_semiring_mt::<MinPlus<i32>>(&graph).unwrap().cells(), squared.cells());
+    assert_eq!(MatMulPool::new(3).mul_semiring::<MinPlus<i32>>(&graph, &graph).unwrap().cells(), squared.cells());
+
+    let floats = Matrix::new(1, 2, vec![1.5, f64::INFINITY]).unwrap();
+    let col = Matrix::new(2, 1, vec![f64::INFINITY, 2.0]).unwrap();
+    assert_eq!(floats.mul_semiring::<MinPlus<f64>>(&col).unwrap().cells(), &[f64::INFINITY]);
+}
+
+#[test]
+fn max_plus_and_boolean_products() {
+    let ninf = i64::MIN;
+    let durations = Matrix::new(2, 2, vec![3i64, ninf, 5, 2]).unwrap();
+    let start = Matrix::new(2, 1, vec![0i64, 1]).unwrap();
+    assert_eq!(durations.mul_semiring::<MaxPlus<i64>>(&start).unwrap().cells(), &[3, 5]);
+
+    // 0 -> 1 -> 2, and nothing leaves 2.
+    let edges = matrix(3, 3, vec![0, 7, 0, 0, 0, 1, 0, 0, 0]);
+    let two_steps = edges.mul_semiring::<Boolean<i32>>(&edges).unwrap();
+    assert_eq!(two_steps.cells(), &[0, 0, 1, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(edges.mul_semiring_mt::<Boolean<i32>>(&edges).unwrap().cells(), two_steps.cells());
+
+    assert_eq!(
+        edges.mul_semiring::<Boolean<i32>>(&matrix(2, 2, vec![1, 0, 0, 1])).err(),
+        Some(MatrixError::ShapeMismatch { left: (3, 3), right: (2, 2) })
+    );
+}
+
+#[test]
+fn bit_matrix_products_match_matrix_products() {
+    // Spans several words per row and a partial group of four Russians.
+    let a = matrix_multiplication::generate_matrix(70, 133);
+    let b = matrix_multiplication::generate_matrix(133, 90);
+    let (a, b) = (odd_cells(&a), odd_cells(&b));
+    let (bits_a, bits_b) = (BitMatrix::from_matrix(&a), BitMatrix::from_matrix(&b));
+
+    assert_eq!(bits_a.to_matrix::<i32>().cells(), a.cells());
+
+    let gf2: Vec<i32> = a.mul(&b).unwrap().cells().iter().map(|cell| cell & 1).collect();
+    assert_eq!(bits_a.mul_gf2(&bits_b).unwrap().to_matrix::<i32>().cells(), &gf2[..]);
+
+    let boolean = a.mul_semiring::<Boolean<i32>>(&b).unwrap();
+    assert_eq!(bits_a.mul_bool(&bits_b).unwrap().to_matrix::<i32>().cells(), boolean.cells());
+
+    assert_eq!(
+        bits_a.mul_bool(&bits_a).err(),
+        Some(MatrixError::ShapeMismatch { left: (70, 133), right: (70, 133) })
+    );
+}
+
+/// Ones where `m` is odd and zeros elsewhere.
+fn odd_cells(m: &Matrix<i32>) -> Matrix<i32> {
+    Matrix::new(m.rows(), m.cols(), m.cells().iter().map(|cell| cell & 1).collect()).unwrap()
+}
+
+#[test]
+fn transitive_closure_follows_paths() {
+    // 0 -> 1 -> 2 -> 0 is a cycle, 2 -> 3, and nothing leaves 3.
+    let edges = matrix(4, 4, vec![0, 1, 0, 0, 0, 0, 5, 0, 1, 0, 0, 1, 0, 0, 0, 0]);
+
+    let closure = edges.transitive_closure().unwrap();
+    assert_eq!(closure.cells(), &[1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0]);
+
+    let mut bits = BitMatrix::zeros(2, 3);
+    assert_eq!(bits.set(1, 2, true), Ok(false));
+    assert_eq!(bits.get(1, 2), Ok(true));
+    assert_eq!(bits.get(2, 0).err(), Some(MatrixError::IndexOutOfBounds { row: 2, col: 0, rows: 2, cols: 3 }));
+    assert_eq!(bits.transitive_closure().err(), Some(MatrixError::NotSquare { rows: 2, cols: 3 }));
+}